@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use lgs_lib::matrix::matrix::Matrix;
+
+fn create_matrix(n: usize, seed: usize) -> Matrix {
+    let data = (0..n)
+        .map(|i| (0..n).map(|j| ((i * 31 + j * 17 + seed) % 101) as f64 / 101.0).collect())
+        .collect();
+    Matrix::from_data(data)
+}
+
+fn bench_matmul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matmul");
+    for &n in &[32, 128, 256, 512] {
+        let a = create_matrix(n, 1);
+        let b = create_matrix(n, 2);
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_naive(black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("blocked", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_blocked(black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("strassen", n), &n, |bench, _| {
+            bench.iter(|| black_box(&a).mul_strassen(black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_matmul);
+criterion_main!(benches);
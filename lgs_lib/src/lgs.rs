@@ -1,64 +1,97 @@
 /// # Description:
 /// Linear Gaussian Separation (LGS),
 /// is a method for solving a system of linear equations against a given set of variables.
+#[allow(clippy::module_inception)]
 pub mod lgs {
     pub use crate::matrix::matrix::*;
+    use std::ops::Range;
 
+    pub mod banded;
+    pub mod eigen;
+    pub mod functions;
+    pub mod projection;
+    pub mod trace;
+
+    pub use self::banded::{banded_lu_decompose, solve_tridiagonal, BandedLu};
+    pub use self::eigen::{eigen_decompose, eigenvalues, EigenDecomposition};
+    pub use self::projection::least_squares;
+    pub use self::trace::{EliminationObserver, EliminationTrace};
 
     /// # Caclulate the inverse of a matrix.
-    /// An inverse of a matrix is the matrix that results in a normal matrix when multiplied with the matrix. 
+    /// An inverse of a matrix is the matrix that results in a normal matrix when multiplied with the matrix.
     /// A normal matrix is a matrix where all values but the main diagonal are 0.
     /// The main diagonal holds only values that are 1.0;
-    /// 
+    ///
     /// # Parameters:
-    /// - m: Matrix - The matrix
-    pub fn inverse(m: Matrix) -> Matrix {
+    /// - m: Matrix - The matrix, or one of the structured matrix types
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the matrix is not square after removing linear dependent rows, or singular.
+    pub fn inverse<T: Scalar>(m: impl Into<Matrix<T>>) -> Matrix<T> {
+        inverse_observed(m.into(), &mut Recorder { observer: None })
+    }
+
+    /// # Inverse traced
+    ///
+    /// Like `inverse`, reporting every row operation on the augmented matrix `[A | I]`
+    /// to the observer, e.g. an `EliminationTrace`.
+    pub fn inverse_traced<T: Scalar>(
+        m: impl Into<Matrix<T>>,
+        observer: &mut dyn EliminationObserver<T>,
+    ) -> Matrix<T> {
+        inverse_observed(m.into(), &mut Recorder { observer: Some(observer) })
+    }
+
+    fn inverse_observed<T: Scalar>(m: Matrix<T>, rec: &mut Recorder<T>) -> Matrix<T> {
         let mut m = m.remove_linear_dependent_rows();
         if m.width != m.height {
             panic!("Matrix must be square");
         }
-        
+
         let mut v = Matrix::new(m.height, m.height);
         for i in 0..m.height {
-         v[i][i] = 1.0;
+            v[i][i] = T::one();
         }
+        rec.begin(|| m.hstack(&v), m.width);
 
-         // shadow as mutable
         // iterate down
         for i in 0..m.height {
-            for j in (i + 1)..m.height {
-            
-                let alpha: f64 =  m[j][i]/ m[i][i];
-                let mut row = m[i].clone();
-                let mut v_row = v[j].clone();
-
-                for i in 0..row.len(){
-                    row[i] *= -alpha;
-                    v_row[i] *= -alpha;
+            if let Some(r) = pivot_if_zero(i, &mut m) {
+                v.swap_rows(i, r);
+                rec.record(RowOp::Swap(i, r), || m.hstack(&v));
+            }
+            let below = (i + 1)..m.height;
+            for rows in batches(below, rec) {
+                let alphas = eliminate(i, rows.clone(), &mut m);
+                subtract_multiples(&mut v, i, rows.clone(), &alphas);
+                for (j, alpha) in rows.zip(alphas) {
+                    rec.record_elimination(j, i, alpha, || m.hstack(&v));
                 }
-    
-                m.add_to_row(j, row);
-                v.add_to_row(j, v_row);
             }
         }
         // iterate up
-        for i in (0..m.height).rev().step_by(1) {
-            for j in 0..i {
-                let alpha: f64 = m[j][i] / m[i][i];
-                let row = m[i].iter().map(|f| f * -alpha).collect();
-                let v_row = v[i].iter().map(|f| f * -alpha).collect();
-                m.add_to_row(j, row);
-                v.add_to_row(j, v_row);
+        for i in (0..m.height).rev() {
+            for rows in batches(0..i, rec) {
+                let alphas = eliminate(i, rows.clone(), &mut m);
+                subtract_multiples(&mut v, i, rows.clone(), &alphas);
+                for (j, alpha) in rows.zip(alphas) {
+                    rec.record_elimination(j, i, alpha, || m.hstack(&v));
+                }
             }
         }
         // normalize
         for i in 0..v.height {
-            let a = 1.0/m[i][i]; 
-            for k in 0..m.width {
-                if v[i][k] == 0.0 {continue;}
-                if v[i][k] == -0.0 {m[i][k] = 0.0; continue;}
-
-                v[i][k] *= a;
+            let a = T::one() / m[i][i].clone();
+            for k in 0..v.width {
+                v[i][k] = v[i][k].clone() * a.clone();
+            }
+            if rec.is_active() && a != T::one() {
+                // the left half is only needed for the trace
+                for k in 0..m.width {
+                    m[i][k] = m[i][k].clone() * a.clone();
+                }
+                rec.record(RowOp::Scale { row: i, factor: a }, || m.hstack(&v));
             }
         }
         v
@@ -69,21 +102,37 @@ pub mod lgs {
     /// Solves a system of linear equations. If the Matrix contains linear dependencies,
     /// they will be removed from the matrix and the system will be solved.
     ///
-    /// If the system cannot be solved, an error will be returned.
+    /// If the system has no unique solution, it panics.
     ///
     /// # Parameters
     ///
-    /// m : Matrix the coefficients of the system
-    /// v: `Vec<f64>` vector to solve against
+    /// m : Matrix the coefficients of the system, or one of the structured matrix types
+    /// v: `Vector<T>` (or `Vec<T>`) vector to solve against
     ///
     /// # Panics if
     ///
-    /// Panics if the matrix provided is not of the size of the vector or square.
-    pub fn solve(m: Matrix, v: Vec<f64>) -> (Matrix, Vec<f64>) {
+    /// Panics if the matrix provided is not of the size of the vector or square,
+    /// or if it is singular.
+    pub fn solve<T: Scalar>(m: impl Into<Matrix<T>>, v: impl Into<Vector<T>>) -> (Matrix<T>, Vector<T>) {
+        solve_observed(m.into(), v.into(), &mut Recorder { observer: None })
+    }
+
+    /// # Solve traced
+    ///
+    /// Like `solve`, reporting every row operation on the augmented matrix `[A | b]`
+    /// to the observer, e.g. an `EliminationTrace`.
+    pub fn solve_traced<T: Scalar>(
+        m: impl Into<Matrix<T>>,
+        v: impl Into<Vector<T>>,
+        observer: &mut dyn EliminationObserver<T>,
+    ) -> (Matrix<T>, Vector<T>) {
+        solve_observed(m.into(), v.into(), &mut Recorder { observer: Some(observer) })
+    }
+
+    fn solve_observed<T: Scalar>(m: Matrix<T>, v: Vector<T>, rec: &mut Recorder<T>) -> (Matrix<T>, Vector<T>) {
         let mut m = m.remove_linear_dependent_rows();
-        let mut v = v; // shadow as mutable
-        
-        // let mut m = m;
+        let mut v = v;
+
         if v.len() != m.height {
             panic!("Matrix and vector lengths do not match");
         }
@@ -91,64 +140,398 @@ pub mod lgs {
         if m.width != m.height {
             panic!("Matrix must be square");
         }
+        rec.begin(|| augment(&m, &v), m.width);
 
         // iterate down
         for i in 0..m.height {
-            calculate_sub_matrix_down(i, &mut m, &mut v);
+            calculate_sub_matrix_down(i, &mut m, &mut v, rec);
         }
         // iterate up
-        for i in (0..m.height).rev().step_by(1) {
-            calculate_submatrix_up(i, &mut m, &mut v);
+        for i in (0..m.height).rev() {
+            calculate_submatrix_up(i, &mut m, &mut v, rec);
         }
         // normalize
-        normalize(&mut v, &mut m);
+        normalize(&mut v, &mut m, rec);
         (m, v)
     }
 
-    fn normalize(v: &mut Vec<f64>, m: &mut Matrix) {
-        // let mut result = vec![0.0; v.len()];
-        for i in 0..v.len() {
-            let a = 1.0/m[i][i]; 
-            for k in 0..m.width {
-                if m[i][k] == 0.0 {continue;}
-                if m[i][k] == -0.0 {m[i][k] = 0.0; continue;}
+    /// # LU decomposition
+    ///
+    /// The factorization `P·A = L·U` of a square matrix with partial pivoting,
+    /// where `L` is unit lower triangular and `U` is upper triangular.
+    /// Both factors are stored in a single matrix.
+    #[derive(Debug, Clone)]
+    pub struct LuDecomposition<T> {
+        lu: Matrix<T>,
+        perm: Vec<usize>,
+        swaps: usize,
+        /// Pivots up to `n·ε·‖A‖∞` count as zero, see [`Matrix::zero_tolerance`].
+        tol: f64,
+    }
 
-                m[i][k] *= a;
+    /// # LU decompose
+    ///
+    /// Factorizes the matrix into `P·A = L·U`, choosing the entry with the
+    /// largest absolute value in each column as pivot.
+    /// Works for every scalar type, including complex numbers.
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the matrix is not square.
+    pub fn lu_decompose<T: Scalar>(m: impl Into<Matrix<T>>) -> LuDecomposition<T> {
+        let m = m.into();
+        if m.width != m.height {
+            panic!("Matrix must be square");
+        }
+        let n = m.height;
+        let tol = m.zero_tolerance();
+        let mut lu = m;
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let mut p = k;
+            for i in (k + 1)..n {
+                if lu[i][k].abs() > lu[p][k].abs() {
+                    p = i;
+                }
+            }
+            if p != k {
+                lu.swap_rows(k, p);
+                perm.swap(k, p);
+                swaps += 1;
             }
-            v[i] *= a;
+            if lu[k][k] == T::zero() {
+                continue;
+            }
+
+            let pivot = lu[k].clone();
+            let work = (n - k) * (n - k);
+            parallel::for_each_row(&mut lu.rows_mut()[k + 1..], work, |_, row| {
+                let l = row[k].clone() / pivot[k].clone();
+                for j in (k + 1)..n {
+                    row[j] = row[j].clone() - l.clone() * pivot[j].clone();
+                }
+                row[k] = l;
+            });
         }
+
+        LuDecomposition { lu, perm, swaps, tol }
     }
 
-    fn calculate_submatrix_up(i: usize, m: &mut Matrix, v: &mut Vec<f64>) {
-        for j in 0..i {
-            let alpha: f64 = m[j][i] / m[i][i];
-            v[j] -= alpha * v[i];
-            let row = m[i].iter().map(|f| f * -alpha).collect();
-            m.add_to_row(j, row);
+    impl<T: Scalar> LuDecomposition<T> {
+        /// The unit lower triangular factor `L`.
+        pub fn l(&self) -> Matrix<T> {
+            let n = self.lu.height;
+            let mut l = Matrix::new(n, n);
+            for i in 0..n {
+                l[i][..i].clone_from_slice(&self.lu[i][..i]);
+                l[i][i] = T::one();
+            }
+            l
+        }
+
+        /// The upper triangular factor `U`.
+        pub fn u(&self) -> Matrix<T> {
+            let n = self.lu.height;
+            let mut u = Matrix::new(n, n);
+            for i in 0..n {
+                u[i][i..].clone_from_slice(&self.lu[i][i..]);
+            }
+            u
+        }
+
+        /// The row permutation: row `i` of `P·A` is row `permutation()[i]` of `A`.
+        pub fn permutation(&self) -> &[usize] {
+            &self.perm
+        }
+
+        /// A matrix is singular if `U` has a zero on its diagonal.
+        /// For floating point scalars, pivots up to `n·ε·‖A‖∞` count as zero,
+        /// so the result does not depend on the scale of the matrix.
+        pub fn is_singular(&self) -> bool {
+            (0..self.lu.height).any(|i| echelon::is_negligible(&self.lu[i][i], self.tol))
+        }
+
+        /// The determinant, the product of the diagonal of `U` with the sign of the permutation.
+        pub fn det(&self) -> T {
+            let mut det = T::one();
+            for i in 0..self.lu.height {
+                det = det * self.lu[i][i].clone();
+            }
+            if self.swaps % 2 == 1 {
+                -det
+            } else {
+                det
+            }
+        }
+
+        /// Solves `A·x = b` by forward and back substitution.
+        ///
+        /// # Panics if
+        ///
+        /// Panics if the length of `b` does not match or the matrix is singular.
+        pub fn solve(&self, b: &[T]) -> Vector<T> {
+            let n = self.lu.height;
+            if b.len() != n {
+                panic!("Matrix and vector lengths do not match");
+            }
+            if self.is_singular() {
+                panic!("Matrix is singular");
+            }
+
+            let mut x: Vector<T> = self.perm.iter().map(|&p| b[p].clone()).collect();
+            for i in 0..n {
+                for j in 0..i {
+                    x[i] = x[i].clone() - self.lu[i][j].clone() * x[j].clone();
+                }
+            }
+            for i in (0..n).rev() {
+                for j in (i + 1)..n {
+                    x[i] = x[i].clone() - self.lu[i][j].clone() * x[j].clone();
+                }
+                x[i] = x[i].clone() / self.lu[i][i].clone();
+            }
+            x
         }
     }
 
-    fn calculate_sub_matrix_down(i: usize, m: &mut Matrix, v: &mut Vec<f64>) {
-        for j in (i + 1)..m.height {
-            
-            let alpha: f64 =  m[j][i]/ m[i][i];
-            v[j] -= alpha * v[i];
+    /// # Solve LU
+    ///
+    /// Solves a square system of linear equations with an LU decomposition with partial pivoting.
+    /// Unlike `solve`, no linear dependent rows are removed, so singular systems panic.
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the matrix is not square, singular, or does not match the length of the vector.
+    pub fn solve_lu<T: Scalar>(m: impl Into<Matrix<T>>, b: impl Into<Vector<T>>) -> Vector<T> {
+        lu_decompose(m).solve(&b.into())
+    }
+
+    /// # Solve
+    ///
+    /// Solving `A·x = b` with the algorithm that fits the structure of `A`:
+    /// O(n) for diagonal, O(n²) substitution for triangular matrices and LU for everything else.
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the length of `b` does not match or the matrix is singular.
+    pub trait Solve<T> {
+        fn solve(&self, b: &[T]) -> Vector<T>;
+    }
+
+    impl<T: Scalar> Solve<T> for Matrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            lu_decompose(self.clone()).solve(b)
+        }
+    }
 
-            let mut row = m[i].clone();
-            for i in 0..row.len(){
-                row[i] *= -alpha;
+    impl<T: Scalar> Solve<T> for LuDecomposition<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            LuDecomposition::solve(self, b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for DiagonalMatrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            DiagonalMatrix::solve(self, b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for UpperTriangular<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            self.back_substitute(b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for LowerTriangular<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            self.forward_substitute(b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for SymmetricMatrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            lu_decompose(self.clone()).solve(b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for BandedMatrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            banded_lu_decompose(self).solve(b)
+        }
+    }
+
+    /// Uses the Levinson recursion, and LU if a leading submatrix is singular.
+    impl<T: Scalar> Solve<T> for ToeplitzMatrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            self.levinson(b)
+                .unwrap_or_else(|| lu_decompose(self.clone()).solve(b))
+        }
+    }
+
+    impl<T: fft::FftScalar> Solve<T> for CirculantMatrix<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            CirculantMatrix::solve(self, b)
+        }
+    }
+
+    impl<T: Scalar> Solve<T> for BandedLu<T> {
+        fn solve(&self, b: &[T]) -> Vector<T> {
+            BandedLu::solve(self, b)
+        }
+    }
+
+    /// Swap a row with a non-zero entry in column `i` onto the diagonal,
+    /// if the current pivot `m[i][i]` is zero.
+    /// Returns the row that was swapped with `i`, so the right hand side can follow.
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the pivot and every entry below it are exactly zero, the matrix is singular.
+    fn pivot_if_zero<T: Scalar>(i: usize, m: &mut Matrix<T>) -> Option<usize> {
+        if !m[i][i].is_zero() {
+            return None;
+        }
+        match ((i + 1)..m.height).find(|&r| !m[r][i].is_zero()) {
+            Some(r) => {
+                m.swap_rows(i, r);
+                Some(r)
+            }
+            None if m[i][i] == T::zero() => panic!("Matrix is singular"),
+            None => None,
+        }
+    }
+
+    /// Passes the row operations on to the observer, if there is one.
+    /// The augmented matrix is only built when it is needed.
+    struct Recorder<'a, T> {
+        observer: Option<&'a mut dyn EliminationObserver<T>>,
+    }
+
+    impl<T: Scalar> Recorder<'_, T> {
+        fn is_active(&self) -> bool {
+            self.observer.is_some()
+        }
+
+        fn begin(&mut self, augmented: impl FnOnce() -> Matrix<T>, split: usize) {
+            if let Some(o) = self.observer.as_mut() {
+                o.begin(&augmented(), split);
+            }
+        }
+
+        fn record(&mut self, op: RowOp<T>, augmented: impl FnOnce() -> Matrix<T>) {
+            if let Some(o) = self.observer.as_mut() {
+                o.step(&op, &augmented());
+            }
+        }
+
+        /// `R_target ← R_target - alpha·R_source`, eliminations with a zero factor are left out.
+        fn record_elimination(&mut self, target: usize, source: usize, alpha: T, augmented: impl FnOnce() -> Matrix<T>) {
+            if alpha != T::zero() {
+                self.record(RowOp::AddMultiple { target, source, factor: -alpha }, augmented);
+            }
+        }
+    }
+
+    /// The matrix with the vector as an additional column.
+    fn augment<T: Scalar>(m: &Matrix<T>, v: &[T]) -> Matrix<T> {
+        let mut column = Matrix::new(v.len(), 1);
+        for (i, x) in v.iter().enumerate() {
+            column[i][0] = x.clone();
+        }
+        m.hstack(&column)
+    }
+
+    fn normalize<T: Scalar>(v: &mut [T], m: &mut Matrix<T>, rec: &mut Recorder<T>) {
+        for i in 0..v.len() {
+            let a = T::one() / m[i][i].clone();
+            for k in 0..m.width {
+                if m[i][k].is_zero() {
+                    m[i][k] = T::zero();
+                    continue;
+                }
+
+                m[i][k] = m[i][k].clone() * a.clone();
+            }
+            v[i] = v[i].clone() * a.clone();
+            if a != T::one() {
+                rec.record(RowOp::Scale { row: i, factor: a }, || augment(m, v));
             }
+        }
+    }
 
-            m.add_to_row(j, row);
+    fn calculate_submatrix_up<T: Scalar>(i: usize, m: &mut Matrix<T>, v: &mut [T], rec: &mut Recorder<T>) {
+        eliminate_with_vector(i, 0..i, m, v, rec);
+    }
+
+    fn calculate_sub_matrix_down<T: Scalar>(i: usize, m: &mut Matrix<T>, v: &mut [T], rec: &mut Recorder<T>) {
+        if let Some(r) = pivot_if_zero(i, m) {
+            v.swap(i, r);
+            rec.record(RowOp::Swap(i, r), || augment(m, v));
         }
+        let below = (i + 1)..m.height;
+        eliminate_with_vector(i, below, m, v, rec);
     }
 
+    fn eliminate_with_vector<T: Scalar>(
+        i: usize,
+        targets: Range<usize>,
+        m: &mut Matrix<T>,
+        v: &mut [T],
+        rec: &mut Recorder<T>,
+    ) {
+        for rows in batches(targets, rec) {
+            let alphas = eliminate(i, rows.clone(), m);
+            for (j, alpha) in rows.zip(alphas) {
+                v[j] = v[j].clone() - alpha.clone() * v[i].clone();
+                rec.record_elimination(j, i, alpha, || augment(m, v));
+            }
+        }
+    }
 
-    
+    /// The rows to eliminate in one go, one at a time if every operation is recorded.
+    fn batches<T: Scalar>(targets: Range<usize>, rec: &Recorder<T>) -> Vec<Range<usize>> {
+        if rec.is_active() {
+            targets.map(|j| j..j + 1).collect()
+        } else {
+            vec![targets]
+        }
+    }
 
+    /// Clear column `i` in the target rows with row `i`, returning the multiples of it
+    /// that were subtracted, so the right hand side can follow.
+    fn eliminate<T: Scalar>(i: usize, targets: Range<usize>, m: &mut Matrix<T>) -> Vec<T> {
+        let alphas: Vec<T> = targets.clone().map(|j| m[j][i].clone() / m[i][i].clone()).collect();
+        subtract_multiples(m, i, targets, &alphas);
+        alphas
+    }
 
+    /// `R_j ← R_j - alpha_j·R_i` for the target rows, in parallel for large matrices.
+    fn subtract_multiples<T: Scalar>(m: &mut Matrix<T>, i: usize, targets: Range<usize>, alphas: &[T]) {
+        let pivot = m[i].clone();
+        let work = targets.len() * m.width;
+        parallel::for_each_row(&mut m.rows_mut()[targets], work, |k, row| {
+            for (x, p) in row.iter_mut().zip(&pivot) {
+                *x = x.clone() - alphas[k].clone() * p.clone();
+            }
+        });
+    }
 }
 
 #[cfg(test)]
 mod lgs_test;
+#[cfg(test)]
 mod lgs_inverse_test;
+#[cfg(test)]
+mod lgs_lu_test;
+#[cfg(test)]
+mod lgs_projection_test;
+#[cfg(test)]
+mod lgs_banded_test;
+#[cfg(test)]
+mod lgs_eigen_test;
+#[cfg(test)]
+mod lgs_functions_test;
+#[cfg(test)]
+mod lgs_trace_test;
@@ -0,0 +1,239 @@
+use super::{BandedMatrix, Scalar, Vector};
+
+/// # Solve tridiagonal
+///
+/// Solves `A·x = rhs` for a tridiagonal matrix in O(n) without building the dense matrix.
+///
+/// The Thomas algorithm eliminates without pivoting, which is only stable if the matrix
+/// is diagonally dominant. For every other matrix the system is solved with the
+/// pivoted banded LU decomposition instead, which is O(n) as well.
+///
+/// # Parameters
+///
+/// sub: the `n - 1` entries below the diagonal
+/// diag: the `n` entries on the diagonal
+/// sup: the `n - 1` entries above the diagonal
+/// rhs: the right hand side
+///
+/// # Panics if
+///
+/// Panics if the lengths do not match or the matrix is singular.
+pub fn solve_tridiagonal<T: Scalar>(sub: &[T], diag: &[T], sup: &[T], rhs: &[T]) -> Vector<T> {
+    let n = diag.len();
+    if rhs.len() != n || sub.len() + 1 != n.max(1) || sup.len() + 1 != n.max(1) {
+        panic!("Matrix and vector lengths do not match");
+    }
+    if n == 0 {
+        return Vector::from_data(vec![]);
+    }
+
+    if is_diagonally_dominant(sub, diag, sup) {
+        return thomas(sub, diag, sup, rhs);
+    }
+
+    let mut b = BandedMatrix::new(n, 1, 1);
+    for i in 0..n {
+        b.set(i, i, diag[i].clone());
+        if i + 1 < n {
+            b.set(i + 1, i, sub[i].clone());
+            b.set(i, i + 1, sup[i].clone());
+        }
+    }
+    banded_lu_decompose(&b).solve(rhs)
+}
+
+/// `|d_i| >= |l_i| + |u_i|` in every row, and strictly in at least one.
+fn is_diagonally_dominant<T: Scalar>(sub: &[T], diag: &[T], sup: &[T]) -> bool {
+    let mut strict = false;
+    for (i, d) in diag.iter().enumerate() {
+        let off = if i > 0 { sub[i - 1].abs() } else { 0.0 }
+            + if i < sup.len() { sup[i].abs() } else { 0.0 };
+        if d.abs() < off {
+            return false;
+        }
+        strict |= d.abs() > off;
+    }
+    strict
+}
+
+fn thomas<T: Scalar>(sub: &[T], diag: &[T], sup: &[T], rhs: &[T]) -> Vector<T> {
+    let n = diag.len();
+    let mut c: Vec<T> = Vec::with_capacity(n);
+    let mut x: Vector<T> = Vector::from(rhs);
+
+    for i in 0..n {
+        let mut d = diag[i].clone();
+        if i > 0 {
+            d = d - sub[i - 1].clone() * c[i - 1].clone();
+            x[i] = x[i].clone() - sub[i - 1].clone() * x[i - 1].clone();
+        }
+        if d.is_zero() {
+            panic!("Matrix is singular");
+        }
+        c.push(if i + 1 < n {
+            sup[i].clone() / d.clone()
+        } else {
+            T::zero()
+        });
+        x[i] = x[i].clone() / d;
+    }
+    for i in (0..n - 1).rev() {
+        x[i] = x[i].clone() - c[i].clone() * x[i + 1].clone();
+    }
+    x
+}
+
+/// A row of the band, holding the columns `start..start + vals.len()`.
+#[derive(Debug, Clone)]
+struct BandRow<T> {
+    start: usize,
+    vals: Vec<T>,
+}
+
+impl<T: Scalar> BandRow<T> {
+    fn get(&self, j: usize) -> T {
+        if j < self.start || j >= self.start + self.vals.len() {
+            return T::zero();
+        }
+        self.vals[j - self.start].clone()
+    }
+}
+
+/// # Banded LU decomposition
+///
+/// `P·A = L·U` of a banded matrix with partial pivoting.
+/// Row interchanges let `U` grow to `kl + ku` super diagonals,
+/// `L` keeps at most `kl` entries per column, so storage and work stay linear in `n`.
+#[derive(Debug, Clone)]
+pub struct BandedLu<T> {
+    u: Vec<BandRow<T>>,
+    l: Vec<Vec<T>>,
+    piv: Vec<usize>,
+    swaps: usize,
+}
+
+/// # Banded LU decompose
+///
+/// Factorizes a banded matrix, choosing the largest entry in each column
+/// among the `kl` rows below the diagonal as pivot.
+pub fn banded_lu_decompose<T: Scalar>(b: &BandedMatrix<T>) -> BandedLu<T> {
+    let (n, kl, ku) = (b.size(), b.kl(), b.ku());
+    let mut rows: Vec<BandRow<T>> = (0..n)
+        .map(|i| {
+            let start = i.saturating_sub(kl);
+            let end = (i + ku + 1).min(n);
+            BandRow {
+                start,
+                vals: (start..end).map(|j| b.get(i, j)).collect(),
+            }
+        })
+        .collect();
+    let mut l = Vec::with_capacity(n);
+    let mut piv = Vec::with_capacity(n);
+    let mut swaps = 0;
+
+    for k in 0..n {
+        let last = (k + kl).min(n - 1);
+        let mut p = k;
+        for i in (k + 1)..=last {
+            if rows[i].get(k).abs() > rows[p].get(k).abs() {
+                p = i;
+            }
+        }
+        if p != k {
+            rows.swap(k, p);
+            swaps += 1;
+        }
+        piv.push(p);
+
+        let pivot = rows[k].get(k);
+        let mut factors = Vec::with_capacity(last - k);
+        for i in (k + 1)..=last {
+            let factor = if pivot.is_zero() {
+                T::zero()
+            } else {
+                rows[i].get(k) / pivot.clone()
+            };
+            let (upper, lower) = rows.split_at_mut(i);
+            eliminate(&mut lower[0], &upper[k], k, &factor);
+            factors.push(factor);
+        }
+        l.push(factors);
+
+        // row k is final, drop the eliminated columns left of the diagonal
+        let row = &mut rows[k];
+        let skip = k - row.start;
+        row.vals.drain(..skip);
+        row.start = k;
+    }
+
+    BandedLu { u: rows, l, piv, swaps }
+}
+
+/// `row -= factor · pivot_row` for the columns right of `k`, and drop column `k` from `row`.
+fn eliminate<T: Scalar>(row: &mut BandRow<T>, pivot_row: &BandRow<T>, k: usize, factor: &T) {
+    let end = pivot_row.start + pivot_row.vals.len();
+    let mut vals = Vec::with_capacity(end.max(row.start + row.vals.len()) - k);
+    for j in (k + 1)..end.max(row.start + row.vals.len()) {
+        let mut v = row.get(j);
+        if !factor.is_zero() {
+            v = v - factor.clone() * pivot_row.get(j);
+        }
+        vals.push(v);
+    }
+    row.start = k + 1;
+    row.vals = vals;
+}
+
+impl<T: Scalar> BandedLu<T> {
+    /// A matrix is singular if `U` has a zero on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        self.u.iter().enumerate().any(|(k, row)| row.get(k).is_zero())
+    }
+
+    /// The determinant, the product of the diagonal of `U` with the sign of the permutation.
+    pub fn det(&self) -> T {
+        let det = self
+            .u
+            .iter()
+            .enumerate()
+            .fold(T::one(), |acc, (k, row)| acc * row.get(k));
+        if self.swaps % 2 == 1 {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Solves `A·x = b` in O(n·(kl + ku)).
+    ///
+    /// # Panics if
+    ///
+    /// Panics if the length of `b` does not match or the matrix is singular.
+    pub fn solve(&self, b: &[T]) -> Vector<T> {
+        let n = self.u.len();
+        if b.len() != n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        if self.is_singular() {
+            panic!("Matrix is singular");
+        }
+
+        let mut x: Vector<T> = Vector::from(b);
+        for k in 0..n {
+            x.swap(k, self.piv[k]);
+            for (d, factor) in self.l[k].iter().enumerate() {
+                x[k + 1 + d] = x[k + 1 + d].clone() - factor.clone() * x[k].clone();
+            }
+        }
+        for k in (0..n).rev() {
+            let row = &self.u[k];
+            let mut xk = x[k].clone();
+            for (a, xj) in row.vals[1..].iter().zip(&x[k + 1..]) {
+                xk = xk - a.clone() * xj.clone();
+            }
+            x[k] = xk / row.vals[0].clone();
+        }
+        x
+    }
+}
@@ -0,0 +1,279 @@
+use super::fft::FftScalar;
+use super::{lu_decompose, Complex, LuDecomposition, Matrix};
+
+/// Maximum number of QR steps per eigenvalue before the iteration gives up.
+const MAX_ITERATIONS: usize = 30;
+
+/// Eigenvector matrices with a larger condition number are treated as singular,
+/// i.e. the matrix is not (numerically) diagonalizable.
+const MAX_CONDITION: f64 = 1e10;
+
+/// # Eigendecomposition
+/// `A = V·Λ·V⁻¹` of a diagonalizable matrix, with the eigenvalues on the diagonal of `Λ`
+/// and the eigenvectors as the columns of `V`.
+/// The decomposition is complex, as real matrices can have complex eigenvalues.
+#[derive(Debug, Clone)]
+pub struct EigenDecomposition {
+    values: Vec<Complex>,
+    vectors: Matrix<Complex>,
+    lu: LuDecomposition<Complex>,
+}
+
+impl EigenDecomposition {
+    /// The eigenvalues, in the order of the eigenvector columns.
+    pub fn eigenvalues(&self) -> &[Complex] {
+        &self.values
+    }
+
+    /// The eigenvectors as columns, each normalized to length 1.
+    pub fn eigenvectors(&self) -> &Matrix<Complex> {
+        &self.vectors
+    }
+
+    /// The coordinates `V⁻¹·x` of `x` in the basis of eigenvectors.
+    pub fn coordinates(&self, x: &[Complex]) -> Vec<Complex> {
+        self.lu.solve(x).into_data()
+    }
+}
+
+/// # Eigen decompose
+///
+/// Computes the eigenvalues and eigenvectors of a square matrix.
+///
+/// The matrix is reduced to the complex Schur form `A = Q·T·Qᴴ` with Householder reflections
+/// and the shifted QR algorithm. The eigenvalues are the diagonal of `T`,
+/// the eigenvectors are found by back substitution in `T`.
+///
+/// Returns `None` if the matrix is not diagonalizable, i.e. the eigenvectors are linear dependent,
+/// or the QR algorithm does not converge.
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square.
+pub fn eigen_decompose<T: FftScalar>(m: &Matrix<T>) -> Option<EigenDecomposition> {
+    let (t, q) = schur_form(m)?;
+    let values: Vec<Complex> = (0..t.height).map(|i| t[i][i]).collect();
+    let vectors = &q * &triangular_eigenvectors(&t);
+    let lu = lu_decompose(vectors.clone());
+    if lu.is_singular() || condition(&vectors, &lu) > MAX_CONDITION {
+        return None;
+    }
+    Some(EigenDecomposition { values, vectors, lu })
+}
+
+/// # Eigenvalues
+///
+/// The eigenvalues of a square matrix, repeated by their algebraic multiplicity.
+/// Unlike [`eigen_decompose`] the matrix does not need to be diagonalizable.
+///
+/// Returns `None` if the QR algorithm does not converge.
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square.
+pub fn eigenvalues<T: FftScalar>(m: &Matrix<T>) -> Option<Vec<Complex>> {
+    let (t, _) = schur_form(m)?;
+    Some((0..t.height).map(|i| t[i][i]).collect())
+}
+
+/// The complex Schur form `A = Q·T·Qᴴ`, returns `(T, Q)`.
+fn schur_form<T: FftScalar>(m: &Matrix<T>) -> Option<(Matrix<Complex>, Matrix<Complex>)> {
+    if m.width != m.height {
+        panic!("Matrix must be square");
+    }
+    let n = m.height;
+    let mut t = Matrix::new(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            t[i][j] = m[i][j].to_complex();
+        }
+    }
+    let mut q = Matrix::identity(n);
+    hessenberg(&mut t, &mut q);
+    schur(&mut t, &mut q)?;
+    Some((t, q))
+}
+
+/// Reduce to upper Hessenberg form with Householder reflections, `A = Q·H·Qᴴ`.
+fn hessenberg(h: &mut Matrix<Complex>, q: &mut Matrix<Complex>) {
+    let n = h.height;
+    for k in 0..n.saturating_sub(2) {
+        let norm = (k + 1..n).map(|i| h[i][k].norm_sqr()).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        // v = x - α·e_1 with α = -e^(i·arg x_1)·|x|, which avoids cancellation
+        let x1 = h[k + 1][k];
+        let alpha = -Complex::from_polar(norm, x1.arg());
+        let mut v: Vec<Complex> = (k + 1..n).map(|i| h[i][k]).collect();
+        v[0] = v[0] - alpha;
+        let v_norm = v.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        for x in v.iter_mut() {
+            *x = *x / Complex::from(v_norm);
+        }
+
+        // H ← (I - 2vvᴴ)·H·(I - 2vvᴴ), Q ← Q·(I - 2vvᴴ)
+        for j in 0..n {
+            let s = (0..v.len()).fold(Complex::from(0.0), |acc, i| acc + v[i].conj() * h[k + 1 + i][j]);
+            for (i, vi) in v.iter().enumerate() {
+                h[k + 1 + i][j] = h[k + 1 + i][j] - Complex::from(2.0) * *vi * s;
+            }
+        }
+        for row in 0..n {
+            reflect_row(&mut h[row][k + 1..], &v);
+            reflect_row(&mut q[row][k + 1..], &v);
+        }
+    }
+}
+
+/// `x ← x·(I - 2vvᴴ)` for a row vector `x`.
+fn reflect_row(x: &mut [Complex], v: &[Complex]) {
+    let s = x.iter().zip(v).fold(Complex::from(0.0), |acc, (x, v)| acc + *x * *v);
+    for (x, v) in x.iter_mut().zip(v) {
+        *x = *x - Complex::from(2.0) * s * v.conj();
+    }
+}
+
+/// Reduce a Hessenberg matrix to upper triangular form with shifted QR steps,
+/// accumulating the rotations in `q`. Returns `None` if it does not converge.
+fn schur(t: &mut Matrix<Complex>, q: &mut Matrix<Complex>) -> Option<()> {
+    let n = t.height;
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 1 {
+        // find the start of the unreduced block ending at hi - 1
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let scale = t[lo - 1][lo - 1].norm_sqr().sqrt() + t[lo][lo].norm_sqr().sqrt();
+            if t[lo][lo - 1].norm_sqr().sqrt() <= f64::EPSILON * scale.max(f64::MIN_POSITIVE) {
+                t[lo][lo - 1] = Complex::from(0.0);
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == hi - 1 {
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+
+        iterations += 1;
+        if iterations > MAX_ITERATIONS * n {
+            return None;
+        }
+        let shift = if iterations % 10 == 0 {
+            // exceptional shift to break cycles
+            t[hi - 1][hi - 1] + Complex::from(t[hi - 1][hi - 2].norm_sqr().sqrt())
+        } else {
+            wilkinson_shift(t, hi - 1)
+        };
+        qr_step(t, q, lo, hi, shift);
+    }
+    Some(())
+}
+
+/// The eigenvalue of the trailing 2×2 block of the window ending at `k` that is closer to `t[k][k]`.
+fn wilkinson_shift(t: &Matrix<Complex>, k: usize) -> Complex {
+    let (a, b, c, d) = (t[k - 1][k - 1], t[k - 1][k], t[k][k - 1], t[k][k]);
+    let half = (a - d) / Complex::from(2.0);
+    let root = sqrt(half * half + b * c);
+    let mean = (a + d) / Complex::from(2.0);
+    let (l1, l2) = (mean + root, mean - root);
+    if (l1 - d).norm_sqr() < (l2 - d).norm_sqr() {
+        l1
+    } else {
+        l2
+    }
+}
+
+/// One explicitly shifted QR step on rows and columns `lo..hi` with Givens rotations.
+fn qr_step(t: &mut Matrix<Complex>, q: &mut Matrix<Complex>, lo: usize, hi: usize, shift: Complex) {
+    let n = t.height;
+    for i in lo..hi {
+        t[i][i] = t[i][i] - shift;
+    }
+    let mut rotations = Vec::with_capacity(hi - lo - 1);
+    for k in lo..hi - 1 {
+        let (a, b) = (t[k][k], t[k + 1][k]);
+        let r = (a.norm_sqr() + b.norm_sqr()).sqrt();
+        let (c, s) = if r == 0.0 {
+            (Complex::from(1.0), Complex::from(0.0))
+        } else {
+            (a / Complex::from(r), b / Complex::from(r))
+        };
+        // G = [[c̄, s̄], [-s, c]] zeroes t[k + 1][k]
+        for j in k..n {
+            let (x, y) = (t[k][j], t[k + 1][j]);
+            t[k][j] = c.conj() * x + s.conj() * y;
+            t[k + 1][j] = c * y - s * x;
+        }
+        rotations.push((c, s));
+    }
+    for (k, (c, s)) in (lo..).zip(rotations) {
+        // multiply with Gᴴ from the right
+        for i in 0..(k + 2).min(hi) {
+            rotate_columns(&mut t[i], k, c, s);
+        }
+        for i in 0..n {
+            rotate_columns(&mut q[i], k, c, s);
+        }
+    }
+    for i in lo..hi {
+        t[i][i] = t[i][i] + shift;
+    }
+}
+
+fn rotate_columns(row: &mut [Complex], k: usize, c: Complex, s: Complex) {
+    let (x, y) = (row[k], row[k + 1]);
+    row[k] = x * c + y * s;
+    row[k + 1] = y * c.conj() - x * s.conj();
+}
+
+/// The eigenvectors of an upper triangular matrix as columns, by back substitution.
+fn triangular_eigenvectors(t: &Matrix<Complex>) -> Matrix<Complex> {
+    let n = t.height;
+    let norm = (0..n).map(|i| t[i][i].norm_sqr().sqrt()).fold(0.0, f64::max);
+    // equal eigenvalues are perturbed slightly, as in LAPACK
+    let small = (norm * f64::EPSILON).max(f64::MIN_POSITIVE);
+    let mut y = Matrix::new(n, n);
+    for k in 0..n {
+        let lambda = t[k][k];
+        y[k][k] = Complex::from(1.0);
+        for i in (0..k).rev() {
+            let s = (i + 1..=k).fold(Complex::from(0.0), |acc, j| acc + t[i][j] * y[j][k]);
+            let mut d = t[i][i] - lambda;
+            if d.norm_sqr().sqrt() < small {
+                d = Complex::from(small);
+            }
+            y[i][k] = -s / d;
+        }
+    }
+    let mut v = y;
+    for k in 0..n {
+        let len = (0..n).map(|i| v[i][k].norm_sqr()).sum::<f64>().sqrt();
+        for i in 0..n {
+            v[i][k] = v[i][k] / Complex::from(len);
+        }
+    }
+    v
+}
+
+/// The 1-norm condition number `‖V‖·‖V⁻¹‖`.
+fn condition(v: &Matrix<Complex>, lu: &LuDecomposition<Complex>) -> f64 {
+    let n = v.height;
+    let column_sum = |col: &dyn Fn(usize) -> Complex| (0..n).map(|i| col(i).norm_sqr().sqrt()).sum::<f64>();
+    let norm = (0..n).map(|j| column_sum(&|i| v[i][j])).fold(0.0, f64::max);
+    let inverse_norm = (0..n)
+        .map(|j| {
+            let mut e = vec![Complex::from(0.0); n];
+            e[j] = Complex::from(1.0);
+            let x = lu.solve(&e);
+            column_sum(&|i| x[i])
+        })
+        .fold(0.0, f64::max);
+    norm * inverse_norm
+}
+
+fn sqrt(z: Complex) -> Complex {
+    Complex::from_polar(z.norm_sqr().sqrt().sqrt(), z.arg() / 2.0)
+}
@@ -0,0 +1,206 @@
+use super::{lu_decompose, LuDecomposition, Matrix, Scalar};
+
+/// Degree of the diagonal Padé approximant used by [`Matrix::expm`].
+const PADE_DEGREE: usize = 6;
+
+/// Maximum number of Denman-Beavers iterations before [`Matrix::sqrtm`] gives up.
+const SQRT_MAX_ITERATIONS: usize = 100;
+
+/// Maximum number of square roots [`Matrix::logm`] takes to bring the matrix close to the identity.
+const LOG_MAX_SQUARE_ROOTS: i32 = 64;
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The `n`-th power by repeated squaring, with O(log |n|) matrix products.
+    /// `A^0` is the identity and negative powers are powers of the inverse.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square, or `n` is negative and the matrix is singular.
+    pub fn pow(&self, n: i64) -> Matrix<T> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let mut base = if n < 0 {
+            lu_inverse(self).unwrap_or_else(|| panic!("Matrix is singular"))
+        } else {
+            self.clone()
+        };
+        let mut exp = n.unsigned_abs();
+        let mut result = Matrix::identity(self.height);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    ///# Description:
+    /// The matrix exponential `e^A = Σ A^k / k!`, e.g. `(A·t).expm()` is the
+    /// solution operator of `x' = A·x`.
+    ///
+    /// Uses scaling and squaring: `A` is divided by `2^s` until its 1-norm is at most 1/2,
+    /// the exponential of the scaled matrix is approximated by a degree 6 Padé approximant,
+    /// and the result is squared `s` times.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square, contains infinite or NaN entries, or its 1-norm overflows.
+    pub fn expm(&self) -> Matrix<T> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let n = self.height;
+        let norm = norm_1(self);
+        // the scaling below never reaches a finite norm, and the norm skips NaN entries
+        if !norm.is_finite() || (0..n).any(|i| self[i].iter().any(|x| x.abs().is_nan())) {
+            panic!("Matrix contains non-finite values");
+        }
+        let s = if norm > 0.5 { (norm / 0.5).log2().ceil() as i32 } else { 0 };
+        let x = self.clone() * T::from_f64(0.5f64.powi(s));
+
+        // numerator and denominator of the Padé approximant, N(X) and D(X) = N(-X)
+        let mut num = Matrix::identity(n);
+        let mut den = Matrix::identity(n);
+        let mut power = Matrix::identity(n);
+        let mut c = 1.0;
+        for k in 1..=PADE_DEGREE {
+            c *= (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+            power = &power * &x;
+            let term = power.clone() * T::from_f64(c);
+            num += &term;
+            if k % 2 == 0 {
+                den += &term;
+            } else {
+                den -= &term;
+            }
+        }
+
+        let mut e = solve_columns(&lu_decompose(den), &num);
+        for _ in 0..s {
+            e = &e * &e;
+        }
+        e
+    }
+
+    ///# Description:
+    /// The principal square root, the root whose eigenvalues have positive real part.
+    ///
+    /// Uses the Denman-Beavers iteration `Y ← (Y + Z⁻¹)/2`, `Z ← (Z + Y⁻¹)/2`
+    /// starting from `Y = A`, `Z = I`, which converges quadratically to `Y = √A` and `Z = √A⁻¹`.
+    ///
+    /// The iteration stops when the change is below the rounding error,
+    /// or when it stops decreasing after the iterate has settled, as for ill-conditioned matrices.
+    ///
+    /// Returns `None` if the iteration does not converge, which happens for singular matrices
+    /// and for real matrices with negative eigenvalues, whose principal root is complex.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn sqrtm(&self) -> Option<Matrix<T>> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let half = T::from_f64(0.5);
+        let tol = T::epsilon().max(f64::EPSILON) * self.height.max(1) as f64;
+        let mut y = self.clone();
+        let mut z = Matrix::identity(self.height);
+        let mut last_change = f64::INFINITY;
+
+        for _ in 0..SQRT_MAX_ITERATIONS {
+            let y_inv = lu_inverse(&y)?;
+            let z_inv = lu_inverse(&z)?;
+            let y_next = (&y + &z_inv) * half.clone();
+            z = (&z + &y_inv) * half.clone();
+
+            let change = norm_1(&(&y_next - &y));
+            let scale = norm_1(&y_next);
+            if change <= tol * scale {
+                return Some(y_next);
+            }
+            // for ill-conditioned matrices the iteration stalls at the rounding noise
+            // above `tol`, once it has converged the change no longer decreases
+            if change >= last_change && change <= tol.sqrt() * scale {
+                return Some(y);
+            }
+            last_change = change;
+            y = y_next;
+        }
+        None
+    }
+
+    ///# Description:
+    /// The principal logarithm, the inverse of [`Matrix::expm`] whose eigenvalues
+    /// have imaginary part in `(-π, π)`.
+    ///
+    /// Uses inverse scaling and squaring: square roots are taken until the matrix is close
+    /// to the identity, then `log(B) = 2·atanh((B - I)(B + I)⁻¹)` is summed as a series
+    /// and multiplied by `2^k` for the `k` square roots.
+    ///
+    /// Returns `None` if a square root does not exist, see [`Matrix::sqrtm`].
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn logm(&self) -> Option<Matrix<T>> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let n = self.height;
+        let identity = Matrix::identity(n);
+        let mut b = self.clone();
+        let mut roots = 0;
+        while norm_1(&(&b - &identity)) > 0.25 {
+            if roots == LOG_MAX_SQUARE_ROOTS {
+                return None;
+            }
+            b = b.sqrtm()?;
+            roots += 1;
+        }
+
+        // z = (B - I)(B + I)⁻¹, the factors commute
+        let z = solve_columns(&lu_decompose(&b + &identity), &(&b - &identity));
+        let z2 = &z * &z;
+        let tol = T::epsilon().max(f64::EPSILON);
+        let mut term = z;
+        let mut sum = Matrix::new(n, n);
+        let mut k = 1;
+        loop {
+            let next = term.clone() * T::from_f64(1.0 / k as f64);
+            sum += &next;
+            if norm_1(&next) <= tol * norm_1(&sum) || k > 200 {
+                break;
+            }
+            term = &term * &z2;
+            k += 2;
+        }
+        Some(sum * T::from_f64(2.0f64.powi(roots + 1)))
+    }
+}
+
+/// The maximum absolute column sum.
+fn norm_1<T: Scalar>(m: &Matrix<T>) -> f64 {
+    (0..m.width)
+        .map(|j| (0..m.height).map(|i| m[i][j].abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// Solve `A·X = B` column by column.
+fn solve_columns<T: Scalar>(lu: &LuDecomposition<T>, b: &Matrix<T>) -> Matrix<T> {
+    if b.height == 0 || b.width == 0 {
+        return b.clone();
+    }
+    let b = b.transpose();
+    let columns: Vec<Vec<T>> = (0..b.height).map(|j| lu.solve(&b[j]).into_data()).collect();
+    Matrix::from_data(columns).transpose()
+}
+
+fn lu_inverse<T: Scalar>(m: &Matrix<T>) -> Option<Matrix<T>> {
+    let lu = lu_decompose(m.clone());
+    if lu.is_singular() {
+        return None;
+    }
+    Some(solve_columns(&lu, &Matrix::identity(m.height)))
+}
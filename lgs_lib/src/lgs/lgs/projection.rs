@@ -0,0 +1,146 @@
+use super::eigen::eigenvalues;
+use super::fft::FftScalar;
+use super::{Matrix, Scalar, UpperTriangular, Vector};
+
+/// # Least squares
+///
+/// Solves `min ‖A·x - b‖` for a matrix with linear independent columns,
+/// e.g. an overdetermined system with more equations than unknowns.
+///
+/// Uses the QR decomposition `A = Q·R` from [`Matrix::gram_schmidt`] and solves `R·x = Qᴴ·b`,
+/// which avoids squaring the condition number like the normal equations `AᴴA·x = Aᴴb` do.
+///
+/// Returns `None` if the columns are linear dependent within the tolerance,
+/// then the minimizer is not unique.
+///
+/// # Panics if
+///
+/// Panics if the length of `b` does not match the height of the matrix.
+pub fn least_squares<T: Scalar>(m: &Matrix<T>, b: &[T], tol: f64) -> Option<Vector<T>> {
+    check_len(m, b);
+    let q = m.gram_schmidt(tol);
+    if q.width < m.width {
+        return None;
+    }
+    let qh = q.adjoint();
+    let mut r = &qh * m;
+    for i in 0..r.height {
+        for j in 0..i {
+            r[i][j] = T::zero();
+        }
+    }
+    let r = UpperTriangular::from_matrix(&r)?;
+    Some(r.back_substitute(&(&qh * &Vector::from(b))))
+}
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The orthogonal projection of `v` onto the column space,
+    /// the closest vector to `v` of the form `A·x`.
+    ///
+    /// # Arguments:
+    /// - v: the vector to project, of the length of the columns
+    /// - tol: the rank tolerance, see [`Matrix::gram_schmidt`]
+    ///
+    /// # Panics:
+    /// Panics if the length of `v` does not match the height.
+    pub fn project(&self, v: &[T], tol: f64) -> Vector<T> {
+        check_len(self, v);
+        let v = Vector::from(v);
+        let mut p = Vector::new(self.height);
+        for q in self.column_space(tol) {
+            p.axpy(q.dot(&v), &q);
+        }
+        p
+    }
+
+    ///# Description:
+    /// The orthogonal projector `P = Q·Qᴴ` onto the column space, with an orthonormal basis `Q`.
+    /// `P` is hermitian and idempotent, `P·v` is [`Matrix::project`] and `I - P` projects
+    /// onto the left null space.
+    pub fn projector(&self, tol: f64) -> Matrix<T> {
+        let q = self.gram_schmidt(tol);
+        &q * &q.adjoint()
+    }
+
+    ///# Description:
+    /// The distance `‖v - P·v‖` of `v` from the column space.
+    ///
+    /// # Panics:
+    /// Panics if the length of `v` does not match the height.
+    pub fn span_residual(&self, v: &[T], tol: f64) -> f64 {
+        let p = self.project(v, tol);
+        (Vector::from(v) - p).norm()
+    }
+
+    ///# Description:
+    /// Check whether `v` is a linear combination of the columns,
+    /// i.e. its distance from the column space is at most `tol` times its length.
+    ///
+    /// # Panics:
+    /// Panics if the length of `v` does not match the height.
+    pub fn is_in_span(&self, v: &[T], tol: f64) -> bool {
+        self.span_residual(v, tol) <= tol * Vector::from(v).norm()
+    }
+}
+
+impl<T: FftScalar> Matrix<T> {
+    ///# Description:
+    /// The principal angles between the column spaces of the two matrices, in ascending order.
+    ///
+    /// With orthonormal bases `Qa` and `Qb` the cosines are the singular values of `Qaᴴ·Qb`.
+    /// As the cosine is flat near zero, small angles are taken from the sines instead,
+    /// the singular values of `Qb - Qa·Qaᴴ·Qb`.
+    ///
+    /// There are as many angles as the smaller dimension of the two spaces,
+    /// the angles are zero for the directions the spaces share and `π/2` for orthogonal ones.
+    ///
+    /// # Panics:
+    /// Panics if the heights differ.
+    pub fn principal_angles(&self, other: &Matrix<T>, tol: f64) -> Vec<f64> {
+        if self.height != other.height {
+            panic!("Matrix dimensions do not match");
+        }
+        let (mut qa, mut qb) = (self.gram_schmidt(tol), other.gram_schmidt(tol));
+        if qa.width < qb.width {
+            std::mem::swap(&mut qa, &mut qb);
+        }
+        if qb.width == 0 {
+            return vec![];
+        }
+
+        let m = &qa.adjoint() * &qb;
+        let r = &qb - &(&qa * &m);
+        let mut cos2 = gram_eigenvalues(&m);
+        let mut sin2 = gram_eigenvalues(&r);
+        cos2.sort_by(|a, b| b.total_cmp(a));
+        sin2.sort_by(|a, b| a.total_cmp(b));
+
+        cos2.iter()
+            .zip(sin2)
+            .map(|(&c, s)| {
+                if c > 0.5 {
+                    s.clamp(0.0, 1.0).sqrt().asin()
+                } else {
+                    c.clamp(0.0, 1.0).sqrt().acos()
+                }
+            })
+            .collect()
+    }
+}
+
+/// The eigenvalues of `MᴴM`, the squared singular values of `M`.
+fn gram_eigenvalues<T: FftScalar>(m: &Matrix<T>) -> Vec<f64> {
+    let gram = &m.adjoint() * m;
+    eigenvalues(&gram)
+        .expect("The QR algorithm converges for hermitian matrices")
+        .into_iter()
+        .map(|l| l.re)
+        .collect()
+}
+
+fn check_len<T>(m: &Matrix<T>, v: &[T]) {
+    if v.len() != m.height {
+        panic!("Matrix and vector lengths do not match");
+    }
+}
@@ -0,0 +1,150 @@
+use super::{Matrix, RowOp};
+use crate::matrix::matrix::row_op::latex_value;
+use std::fmt::Display;
+
+/// # Elimination observer
+/// A hook into the elimination of [`solve_traced`](super::solve_traced)
+/// and [`inverse_traced`](super::inverse_traced).
+/// It sees the augmented matrix `[A | b]` (or `[A | I]`) before the first
+/// and after every row operation.
+pub trait EliminationObserver<T> {
+    /// Called once with the augmented matrix before the elimination,
+    /// `split` is the number of columns left of the bar.
+    fn begin(&mut self, _augmented: &Matrix<T>, _split: usize) {}
+
+    /// Called after every row operation with the augmented matrix after it.
+    fn step(&mut self, op: &RowOp<T>, augmented: &Matrix<T>);
+}
+
+/// # Elimination trace
+/// Records every step of an elimination, e.g. to show it to students.
+///
+/// ```
+/// use lgs_lib::lgs::lgs::{solve_traced, EliminationTrace, Matrix};
+///
+/// let mut trace = EliminationTrace::new();
+/// solve_traced(Matrix::from_data(vec![vec![1.0, 2.0], vec![3.0, 4.0]]), vec![5.0, 6.0], &mut trace);
+/// println!("{}", trace.to_text());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EliminationTrace<T = f64> {
+    initial: Option<Matrix<T>>,
+    split: usize,
+    steps: Vec<(RowOp<T>, Matrix<T>)>,
+}
+
+impl<T> Default for EliminationTrace<T> {
+    fn default() -> Self {
+        EliminationTrace {
+            initial: None,
+            split: 0,
+            steps: vec![],
+        }
+    }
+}
+
+impl<T: Clone> EliminationObserver<T> for EliminationTrace<T> {
+    fn begin(&mut self, augmented: &Matrix<T>, split: usize) {
+        self.initial = Some(augmented.clone());
+        self.split = split;
+    }
+
+    fn step(&mut self, op: &RowOp<T>, augmented: &Matrix<T>) {
+        self.steps.push((op.clone(), augmented.clone()));
+    }
+}
+
+impl<T> EliminationTrace<T> {
+    pub fn new() -> EliminationTrace<T> {
+        EliminationTrace::default()
+    }
+
+    /// The augmented matrix before the first operation.
+    pub fn initial(&self) -> Option<&Matrix<T>> {
+        self.initial.as_ref()
+    }
+
+    /// The operations, each with the augmented matrix after it.
+    pub fn steps(&self) -> &[(RowOp<T>, Matrix<T>)] {
+        &self.steps
+    }
+}
+
+impl<T: Clone + Display> EliminationTrace<T> {
+    ///# Description:
+    /// The trace as plain text, every operation followed by the augmented matrix
+    /// with aligned columns, e.g.
+    /// ```text
+    /// R2 ← R2 − 3·R1
+    /// [ 1  2 |  5 ]
+    /// [ 0 -2 | -9 ]
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        if let Some(m) = &self.initial {
+            out.push_str(&text_matrix(m, self.split));
+        }
+        for (op, m) in &self.steps {
+            out.push_str(&format!("\n{}\n{}", op, text_matrix(m, self.split)));
+        }
+        out
+    }
+
+    ///# Description:
+    /// The trace as LaTeX, an `align*` environment with one line per step,
+    /// the operation written over the arrow.
+    pub fn to_latex(&self) -> String {
+        let mut out = String::from("\\begin{align*}\n");
+        if let Some(m) = &self.initial {
+            out.push_str(&format!("& {}", latex_matrix(m, self.split)));
+        }
+        for (op, m) in &self.steps {
+            out.push_str(&format!(
+                " \\\\\n\\xrightarrow{{{}}} & {}",
+                op.to_latex(),
+                latex_matrix(m, self.split)
+            ));
+        }
+        out.push_str("\n\\end{align*}");
+        out
+    }
+}
+
+fn text_matrix<T: Display>(m: &Matrix<T>, split: usize) -> String {
+    let cells: Vec<Vec<String>> = (0..m.height)
+        .map(|i| m[i].iter().map(|x| x.to_string()).collect())
+        .collect();
+    let widths: Vec<usize> = (0..m.width)
+        .map(|j| cells.iter().map(|r| r[j].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let mut out = String::new();
+    for row in &cells {
+        out.push('[');
+        for (j, cell) in row.iter().enumerate() {
+            if j == split {
+                out.push_str(" |");
+            }
+            out.push_str(&format!(" {:>w$}", cell, w = widths[j]));
+        }
+        out.push_str(" ]\n");
+    }
+    out
+}
+
+fn latex_matrix<T: Display>(m: &Matrix<T>, split: usize) -> String {
+    let spec = format!("{}|{}", "c".repeat(split), "c".repeat(m.width - split));
+    let rows: Vec<String> = (0..m.height)
+        .map(|i| {
+            m[i].iter()
+                .map(|x| latex_value(&x.to_string()))
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect();
+    format!(
+        "\\left[\\begin{{array}}{{{}}} {} \\end{{array}}\\right]",
+        spec,
+        rows.join(" \\\\ ")
+    )
+}
@@ -0,0 +1,101 @@
+use crate::lgs::lgs::{banded_lu_decompose, lu_decompose, solve_tridiagonal, Solve};
+use crate::matrix::matrix::{BandedMatrix, Matrix, Rational, Vector};
+use crate::test_util::assert_close;
+
+fn tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64]) -> Matrix {
+    let n = diag.len();
+    let mut m = Matrix::new(n, n);
+    for i in 0..n {
+        m[i][i] = diag[i];
+        if i + 1 < n {
+            m[i + 1][i] = sub[i];
+            m[i][i + 1] = sup[i];
+        }
+    }
+    m
+}
+
+#[test]
+fn test_solve_tridiagonal_heat_equation() {
+    // implicit step of the 1-D heat equation, strictly diagonally dominant
+    let n = 50;
+    let (sub, diag, sup) = (vec![-1.0; n - 1], vec![4.0; n], vec![-1.0; n - 1]);
+    let x: Vec<f64> = (0..n).map(|i| (i as f64 / 7.0).sin()).collect();
+    let rhs = tridiagonal(&sub, &diag, &sup) * &Vector::from_data(x.clone());
+
+    assert_close(&solve_tridiagonal(&sub, &diag, &sup, &rhs), &x, 1e-10);
+}
+
+#[test]
+fn test_solve_tridiagonal_needs_pivoting() {
+    // zero on the diagonal, the Thomas algorithm would divide by zero
+    let (sub, diag, sup) = ([1.0, 1.0], [0.0, 0.0, 1.0], [1.0, 2.0]);
+    let x = [1.0, 2.0, 3.0];
+    let rhs = tridiagonal(&sub, &diag, &sup) * &Vector::from_data(x.to_vec());
+
+    assert_close(&solve_tridiagonal(&sub, &diag, &sup, &rhs), &x, 1e-10);
+}
+
+#[test]
+fn test_solve_tridiagonal_small_pivot_is_accurate() {
+    // not diagonally dominant with a tiny pivot, which ruins the unpivoted elimination
+    let (sub, diag, sup) = ([1.0, 1.0], [1e-17, 1.0, 1.0], [1.0, 1.0]);
+    let x = [1.0, 1.0, 1.0];
+    let rhs = tridiagonal(&sub, &diag, &sup) * &Vector::from_data(x.to_vec());
+
+    assert_close(&solve_tridiagonal(&sub, &diag, &sup, &rhs), &x, 1e-10);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_solve_tridiagonal_singular_panics() {
+    solve_tridiagonal(&[1.0], &[1.0, 1.0], &[1.0], &[1.0, 2.0]);
+}
+
+#[test]
+#[should_panic(expected = "Matrix and vector lengths do not match")]
+fn test_solve_tridiagonal_length_mismatch_panics() {
+    solve_tridiagonal(&[1.0, 1.0], &[1.0, 1.0], &[1.0], &[1.0, 2.0]);
+}
+
+#[test]
+fn test_banded_lu_matches_dense_lu() {
+    let n = 9;
+    let mut m: Matrix = Matrix::new(n, n);
+    for i in 0..n {
+        for j in i.saturating_sub(2)..(i + 2).min(n) {
+            m[i][j] = ((i * 7 + j * 3) % 5) as f64 - 2.0;
+        }
+    }
+    let b = BandedMatrix::from_matrix(&m, 2, 1).unwrap();
+    let lu = banded_lu_decompose(&b);
+    let dense = lu_decompose(m.clone());
+    assert!((lu.det() - dense.det()).abs() < 1e-9);
+
+    let x: Vec<f64> = (0..n).map(|i| i as f64 - 3.0).collect();
+    let rhs = b.mul_vec(&x);
+    assert_close(&lu.solve(&rhs), &x, 1e-10);
+    assert_close(&Solve::solve(&b, &rhs), &x, 1e-10);
+}
+
+#[test]
+fn test_banded_lu_exact_for_rationals() {
+    let r = |v: i64| Rational::from(v);
+    let mut b = BandedMatrix::new(4, 1, 2);
+    for i in 0..4_usize {
+        for j in i.saturating_sub(1)..(i + 3).min(4) {
+            b.set(i, j, r((i as i64 - 2 * j as i64) % 3));
+        }
+    }
+    let lu = banded_lu_decompose(&b);
+    assert_eq!(lu.det(), Matrix::from(b.clone()).det());
+
+    let x = [r(1), r(-2), r(3), r(5)];
+    assert_eq!(lu.solve(&b.mul_vec(&x)), x.to_vec());
+}
+
+#[test]
+fn test_banded_lu_singular() {
+    let b: BandedMatrix = BandedMatrix::from_matrix(&Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]), 1, 1).unwrap();
+    assert!(banded_lu_decompose(&b).is_singular());
+}
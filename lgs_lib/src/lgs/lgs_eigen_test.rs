@@ -0,0 +1,67 @@
+use crate::lgs::lgs::eigen_decompose;
+use crate::matrix::matrix::{Complex, Matrix};
+
+fn assert_decomposes(m: &Matrix) {
+    let e = eigen_decompose(m).unwrap();
+    let n = m.height;
+    let v = e.eigenvectors();
+    // A·v_k = λ_k·v_k
+    for (k, &l) in e.eigenvalues().iter().enumerate() {
+        for i in 0..n {
+            let av = (0..n).fold(Complex::from(0.0), |acc, j| acc + Complex::from(m[i][j]) * v[j][k]);
+            assert!((av - l * v[i][k]).norm_sqr().sqrt() < 1e-10, "{:?}", e);
+        }
+    }
+}
+
+#[test]
+fn test_eigen_decompose_symmetric() {
+    let m = Matrix::from_data(vec![vec![2.0, 1.0, 0.0], vec![1.0, 2.0, 1.0], vec![0.0, 1.0, 2.0]]);
+    assert_decomposes(&m);
+    let mut values: Vec<f64> = eigen_decompose(&m).unwrap().eigenvalues().iter().map(|l| l.re).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let s = 2f64.sqrt();
+    for (l, e) in values.iter().zip([2.0 - s, 2.0, 2.0 + s]) {
+        assert!((l - e).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_eigen_decompose_complex_eigenvalues() {
+    let rotation = Matrix::from_data(vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+    assert_decomposes(&rotation);
+    let e = eigen_decompose(&rotation).unwrap();
+    let mut im: Vec<f64> = e.eigenvalues().iter().map(|l| l.im).collect();
+    im.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((im[0] + 1.0).abs() < 1e-14 && (im[1] - 1.0).abs() < 1e-14);
+}
+
+#[test]
+fn test_eigen_decompose_general() {
+    let m = Matrix::from_data(vec![
+        vec![4.0, -2.0, 1.0, 3.0],
+        vec![1.0, 0.0, 2.0, -1.0],
+        vec![0.5, 3.0, -2.0, 1.0],
+        vec![2.0, 1.0, 0.0, 5.0],
+    ]);
+    assert_decomposes(&m);
+    assert_decomposes(&Matrix::identity(3));
+}
+
+#[test]
+fn test_coordinates() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0], vec![3.0, -4.0]]);
+    let e = eigen_decompose(&m).unwrap();
+    let x = [Complex::from(1.0), Complex::from(2.0)];
+    let c = e.coordinates(&x);
+    for (i, xi) in x.iter().enumerate() {
+        let back = (0..2).fold(Complex::from(0.0), |acc, k| acc + e.eigenvectors()[i][k] * c[k]);
+        assert!((back - *xi).norm_sqr() < 1e-24);
+    }
+}
+
+#[test]
+fn test_eigen_decompose_defective() {
+    let m = Matrix::from_data(vec![vec![2.0, 1.0], vec![0.0, 2.0]]);
+    assert!(eigen_decompose(&m).is_none());
+}
@@ -0,0 +1,122 @@
+use crate::matrix::matrix::{Complex, Matrix, Rational};
+use crate::test_util::assert_matrix_close;
+
+#[test]
+fn test_pow() {
+    let a = Matrix::from_data(vec![vec![1.0, 1.0], vec![1.0, 0.0]]);
+    // Fibonacci numbers
+    assert_eq!(a.pow(10), Matrix::from_data(vec![vec![89.0, 55.0], vec![55.0, 34.0]]));
+    assert_eq!(a.pow(1), a);
+    assert_eq!(a.pow(0), Matrix::identity(2));
+}
+
+#[test]
+fn test_negative_pow_is_exact_for_rationals() {
+    let r = |n: i64| Rational::from(n);
+    let a = Matrix::from_data(vec![vec![r(2), r(1)], vec![r(1), r(1)]]);
+    assert_eq!(&a.pow(-3) * &a.pow(3), Matrix::identity(2));
+    assert_eq!(a.pow(-1), Matrix::from_data(vec![vec![r(1), r(-1)], vec![r(-1), r(2)]]));
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_negative_pow_of_singular_matrix_panics() {
+    Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).pow(-1);
+}
+
+#[test]
+fn test_expm() {
+    // rotation generator: e^(A·t) = [[cos t, sin t], [-sin t, cos t]]
+    let t: f64 = 2.5;
+    let a = Matrix::from_data(vec![vec![0.0, t], vec![-t, 0.0]]);
+    let expected = Matrix::from_data(vec![vec![t.cos(), t.sin()], vec![-t.sin(), t.cos()]]);
+    assert_matrix_close(&a.expm(), &expected, 1e-12);
+
+    // nilpotent: e^N = I + N + N²/2
+    let n = Matrix::from_data(vec![vec![0.0, 1.0, 3.0], vec![0.0, 0.0, 2.0], vec![0.0, 0.0, 0.0]]);
+    let expected = Matrix::from_data(vec![vec![1.0, 1.0, 4.0], vec![0.0, 1.0, 2.0], vec![0.0, 0.0, 1.0]]);
+    assert_matrix_close(&n.expm(), &expected, 1e-13);
+
+    assert_eq!(Matrix::<f64>::new(3, 3).expm(), Matrix::identity(3));
+}
+
+#[test]
+#[should_panic(expected = "Matrix contains non-finite values")]
+fn test_expm_of_infinite_matrix() {
+    Matrix::from_data(vec![vec![f64::INFINITY, 0.0], vec![0.0, 1.0]]).expm();
+}
+
+#[test]
+#[should_panic(expected = "Matrix contains non-finite values")]
+fn test_expm_of_nan_matrix() {
+    Matrix::from_data(vec![vec![1.0, f64::NAN], vec![0.0, 1.0]]).expm();
+}
+
+#[test]
+#[should_panic(expected = "Matrix contains non-finite values")]
+fn test_expm_with_overflowing_norm() {
+    Matrix::from_data(vec![vec![f64::MAX, 0.0], vec![f64::MAX, 0.0]]).expm();
+}
+
+#[test]
+fn test_expm_of_large_diagonal() {
+    let a = Matrix::from_data(vec![vec![10.0, 0.0], vec![0.0, -3.0]]);
+    let e = a.expm();
+    assert!((e[0][0] / 10f64.exp() - 1.0).abs() < 1e-12);
+    assert!((e[1][1] / (-3f64).exp() - 1.0).abs() < 1e-12);
+    assert!(e[0][1].abs() < 1e-12 && e[1][0].abs() < 1e-12);
+}
+
+#[test]
+fn test_expm_complex() {
+    // e^(iθ·I) = (cos θ + i sin θ)·I
+    let theta: f64 = 0.75;
+    let a = Matrix::identity(2) * Complex::new(0.0, theta);
+    let e = a.expm();
+    assert!((e[0][0].re - theta.cos()).abs() < 1e-13);
+    assert!((e[0][0].im - theta.sin()).abs() < 1e-13);
+    assert!(e[0][1].norm_sqr() < 1e-26);
+}
+
+#[test]
+fn test_sqrtm() {
+    let a = Matrix::from_data(vec![vec![4.0, 1.0], vec![0.0, 9.0]]);
+    let root = a.sqrtm().unwrap();
+    assert_matrix_close(&(&root * &root), &a, 1e-12);
+    assert_matrix_close(&root, &Matrix::from_data(vec![vec![2.0, 0.2], vec![0.0, 3.0]]), 1e-12);
+}
+
+fn hilbert(n: usize) -> Matrix {
+    Matrix::from_data((0..n).map(|i| (0..n).map(|j| 1.0 / (i + j + 1) as f64).collect()).collect())
+}
+
+#[test]
+fn test_sqrtm_and_logm_of_ill_conditioned_matrices() {
+    for n in [6, 7] {
+        let a = hilbert(n);
+        let root = a.sqrtm().unwrap();
+        assert_matrix_close(&(&root * &root), &a, 1e-9);
+        assert_matrix_close(&a.logm().unwrap().expm(), &a, 1e-7);
+    }
+}
+
+#[test]
+fn test_sqrtm_without_real_root() {
+    assert!(Matrix::from_data(vec![vec![-1.0, 0.0], vec![0.0, 4.0]]).sqrtm().is_none());
+    assert!(Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).sqrtm().is_none());
+}
+
+#[test]
+fn test_logm_inverts_expm() {
+    let a = Matrix::from_data(vec![vec![0.5, 1.0, 0.0], vec![-1.0, 0.2, 0.3], vec![0.0, 0.1, -0.4]]);
+    assert_matrix_close(&a.expm().logm().unwrap(), &a, 1e-10);
+
+    let b = Matrix::from_data(vec![vec![5.0, 2.0], vec![1.0, 3.0]]);
+    assert_matrix_close(&b.logm().unwrap().expm(), &b, 1e-10);
+    assert!(Matrix::<f64>::identity(1).logm().unwrap()[0][0].abs() < 1e-15);
+}
+
+#[test]
+fn test_logm_without_real_logarithm() {
+    assert!(Matrix::from_data(vec![vec![-2.0, 0.0], vec![0.0, 1.0]]).logm().is_none());
+}
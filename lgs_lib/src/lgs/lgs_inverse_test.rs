@@ -0,0 +1,29 @@
+use crate::lgs::lgs::inverse;
+use crate::matrix::matrix::Matrix;
+use crate::test_util::rationals;
+
+#[test]
+fn test_inverse_of_identity() {
+    let m = Matrix::from_data(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    assert_eq!(inverse(m.clone()), m);
+}
+
+#[test]
+fn test_inverse_of_2x2_matrix() {
+    let m = Matrix::from_data(vec![vec![2.0, 1.0], vec![4.0, 3.0]]);
+    let expected = Matrix::from_data(vec![vec![1.5, -0.5], vec![-2.0, 1.0]]);
+    assert_eq!(inverse(m), expected);
+}
+
+#[test]
+fn test_inverse_with_zero_pivot() {
+    let m = Matrix::from_data(vec![vec![0.0, 2.0], vec![1.0, 0.0]]);
+    let expected = Matrix::from_data(vec![vec![0.0, 1.0], vec![0.5, 0.0]]);
+    assert_eq!(inverse(m), expected);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_inverse_of_singular_rational_matrix() {
+    inverse(rationals(vec![vec![1, 0, 1], vec![0, 1, 1], vec![1, 1, 2]]));
+}
@@ -0,0 +1,109 @@
+use crate::lgs::lgs::{lu_decompose, solve_lu};
+use crate::matrix::matrix::{Complex, Matrix, Scalar};
+use crate::test_util::assert_close;
+
+fn c(re: f64, im: f64) -> Complex {
+    Complex::new(re, im)
+}
+
+#[test]
+fn test_parse_complex() {
+    assert_eq!("3".parse::<Complex>(), Ok(c(3.0, 0.0)));
+    assert_eq!("-2i".parse::<Complex>(), Ok(c(0.0, -2.0)));
+    assert_eq!("1.5 - 2j".parse::<Complex>(), Ok(c(1.5, -2.0)));
+    assert_eq!("-1+i".parse::<Complex>(), Ok(c(-1.0, 1.0)));
+    assert_eq!("1e-3+2e2i".parse::<Complex>(), Ok(c(1e-3, 2e2)));
+    assert!("1+x".parse::<Complex>().is_err());
+}
+
+#[test]
+fn test_adjoint_of_complex_matrix() {
+    let m: Matrix<Complex> = Matrix::from_str("[[1+2i, 3],[-i, 4-1i]]");
+    let expected: Matrix<Complex> = Matrix::from_str("[[1-2i, i],[3, 4+1i]]");
+    assert_eq!(m.adjoint(), expected);
+}
+
+#[test]
+fn test_is_hermitian() {
+    let h: Matrix<Complex> = Matrix::from_str("[[2, 1-i],[1+i, 3]]");
+    assert!(h.is_hermitian());
+    let n: Matrix<Complex> = Matrix::from_str("[[2, 1-i],[1-i, 3]]");
+    assert!(!n.is_hermitian());
+    let s = Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+    assert!(s.is_hermitian());
+}
+
+#[test]
+fn test_is_unitary() {
+    let r = 0.5_f64.sqrt();
+    let u = Matrix::from_data(vec![vec![c(r, 0.0), c(0.0, r)], vec![c(0.0, r), c(r, 0.0)]]);
+    assert!(u.is_unitary(1e-12));
+    assert!(!(u * c(2.0, 0.0)).is_unitary(1e-12));
+}
+
+#[test]
+fn test_lu_factors_reproduce_matrix() {
+    let m = Matrix::from_data(vec![
+        vec![1.0, 2.0, 52.0, 2.0],
+        vec![1.0, 0.0, 1.0, 2.0],
+        vec![3.0, 2.0, 12.0, 2.0],
+        vec![3.0, 5.0, 3.0, 2.0],
+    ]);
+    let lu = lu_decompose(m.clone());
+    let p = Matrix::from_data(lu.permutation().iter().map(|&i| m[i].clone()).collect());
+    let product = lu.l() * lu.u();
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((product[i][j] - p[i][j]).abs() < 1e-12);
+        }
+    }
+    assert!((lu.det() - 684.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_complex_det_matches_cofactor_expansion() {
+    let m: Matrix<Complex> = Matrix::from_str("[[1+i, 2, 0],[3i, -1, 1-i],[2, 1+2i, 4]]");
+    let lu = lu_decompose(m.clone()).det();
+    assert!((lu - m.det()).abs() < 1e-12);
+}
+
+#[test]
+fn test_complex_lu_solve_ac_circuit() {
+    // Two meshes sharing a capacitor: (R + jX_L - jX_C) I1 + jX_C I2 = V, jX_C I1 + (R - jX_C) I2 = 0
+    let (r, xl, xc) = (10.0, 5.0, 8.0);
+    let z = Matrix::from_data(vec![
+        vec![c(r, xl - xc), c(0.0, xc)],
+        vec![c(0.0, xc), c(r, -xc)],
+    ]);
+    let v = vec![c(230.0, 0.0), Complex::zero()];
+    let i = solve_lu(z.clone(), v.clone());
+    let back: Vec<Complex> = (0..2).map(|k| z[k][0] * i[0] + z[k][1] * i[1]).collect();
+    assert_close(&back, &v, 1e-12);
+}
+
+#[test]
+fn test_lu_solve_needs_pivoting() {
+    let m = Matrix::from_data(vec![vec![c(0.0, 0.0), c(1.0, 0.0)], vec![c(0.0, 1.0), c(0.0, 0.0)]]);
+    let x = solve_lu(m, vec![c(2.0, 0.0), c(0.0, 3.0)]);
+    assert_close(&x, &[c(3.0, 0.0), c(2.0, 0.0)], 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_lu_solve_singular_matrix() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    solve_lu(m, vec![1.0, 1.0]);
+}
+
+#[test]
+fn test_lu_singularity_does_not_depend_on_scale() {
+    for scale in [1e-20, 1.0, 1e20] {
+        let id = Matrix::from_data(vec![vec![scale, 0.0], vec![0.0, scale]]);
+        assert!(!lu_decompose(id.clone()).is_singular());
+        let x = solve_lu(id, vec![scale, 2.0 * scale]);
+        assert_close(&x, &[1.0, 2.0], 1e-12);
+
+        let m = Matrix::from_data(vec![vec![scale, 1.0 / 3.0 * scale], vec![3.0 * scale, scale]]);
+        assert!(lu_decompose(m).is_singular());
+    }
+}
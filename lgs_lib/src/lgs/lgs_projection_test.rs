@@ -0,0 +1,86 @@
+use crate::lgs::lgs::least_squares;
+use crate::matrix::matrix::{Complex, Matrix, Vector};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use crate::test_util::assert_close;
+
+fn xy_plane() -> Matrix {
+    Matrix::from_data(vec![vec![1.0, 1.0], vec![0.0, 1.0], vec![0.0, 0.0]])
+}
+
+#[test]
+fn test_least_squares_line_fit() {
+    // fit y = a + b·t through (0, 1), (1, 3), (2, 4), (3, 4)
+    let m = Matrix::from_data(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0], vec![1.0, 3.0]]);
+    let x = least_squares(&m, &[1.0, 3.0, 4.0, 4.0], 1e-10).unwrap();
+    assert_close(&x, &[1.5, 1.0], 1e-12);
+}
+
+#[test]
+fn test_least_squares_rank_deficient() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]);
+    assert!(least_squares(&m, &[1.0, 2.0, 3.0], 1e-10).is_none());
+}
+
+#[test]
+fn test_project() {
+    let p = xy_plane().project(&[3.0, -2.0, 5.0], 1e-10);
+    assert_close(&p, &[3.0, -2.0, 0.0], 1e-14);
+
+    // onto the line through (1, 1)
+    let line = Matrix::from_data(vec![vec![2.0], vec![2.0]]);
+    assert_close(&line.project(&[1.0, 0.0], 1e-10), &[0.5, 0.5], 1e-15);
+}
+
+#[test]
+fn test_projector() {
+    let m: Matrix = Matrix::from_data(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 1.0]]);
+    let p: Matrix = m.projector(1e-10);
+    let p2 = &p * &p;
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((p2[i][j] - p[i][j]).abs() < 1e-14);
+            assert!((p[i][j] - p[j][i]).abs() < 1e-14);
+        }
+    }
+    // the normal (1, -1, 1) is mapped to zero
+    assert!((&p * &Vector::from(vec![1.0, -1.0, 1.0])).norm() < 1e-14);
+    let v = [2.0, 1.0, -3.0];
+    assert_close(&(&p * &Vector::from(v.to_vec())), &m.project(&v, 1e-10), 1e-14);
+}
+
+#[test]
+fn test_is_in_span() {
+    let m = xy_plane();
+    assert!(m.is_in_span(&[5.0, -1.0, 0.0], 1e-10));
+    assert!(!m.is_in_span(&[5.0, -1.0, 1e-3], 1e-10));
+    assert!((m.span_residual(&[5.0, -1.0, 2.0], 1e-10) - 2.0).abs() < 1e-14);
+    assert!(m.is_in_span(&[0.0, 0.0, 0.0], 1e-10));
+}
+
+#[test]
+fn test_principal_angles() {
+    let plane = xy_plane();
+    let tilted = Matrix::from_data(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 1.0]]);
+    assert_close(&plane.principal_angles(&tilted, 1e-10), &[0.0, FRAC_PI_4], 1e-14);
+
+    let z = Matrix::from_data(vec![vec![0.0], vec![0.0], vec![3.0]]);
+    assert_close(&plane.principal_angles(&z, 1e-10), &[FRAC_PI_2], 1e-14);
+    assert_close(&z.principal_angles(&plane, 1e-10), &[FRAC_PI_2], 1e-14);
+}
+
+#[test]
+fn test_small_principal_angle_is_accurate() {
+    let theta: f64 = 1e-9;
+    let a = Matrix::from_data(vec![vec![1.0], vec![0.0]]);
+    let b = Matrix::from_data(vec![vec![theta.cos()], vec![theta.sin()]]);
+    let angles = a.principal_angles(&b, 1e-12);
+    assert!((angles[0] / theta - 1.0).abs() < 1e-6, "{:?}", angles);
+}
+
+#[test]
+fn test_principal_angles_complex() {
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    let a = Matrix::from_data(vec![vec![c(1.0, 0.0)], vec![c(0.0, 0.0)]]);
+    let b = Matrix::from_data(vec![vec![c(0.0, 1.0)], vec![c(0.0, 1.0)]]);
+    assert_close(&a.principal_angles(&b, 1e-10), &[FRAC_PI_4], 1e-14);
+}
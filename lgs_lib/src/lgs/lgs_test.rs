@@ -1,5 +1,6 @@
 use crate::lgs::lgs::solve;
-use crate::matrix::matrix::Matrix;
+use crate::matrix::matrix::{Matrix, Rational};
+use crate::test_util::rationals;
 
 #[test]
 fn test_solve_with_x() {
@@ -18,3 +19,25 @@ fn test_solve_valid_lgs() {
     assert_eq!(res, vec![2.0,-2.0]);
     assert_eq!(m, Matrix::from_data(vec![vec![1.0, 0.0], vec![0.0, 1.0]]));
 }
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_solve_singular_rational_system() {
+    // no two rows are multiples of each other, so none are removed
+    let m = rationals(vec![vec![1, 0, 1], vec![0, 1, 1], vec![1, 1, 2]]);
+    solve(m, vec![Rational::from(1), Rational::from(2), Rational::from(3)]);
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_solve_singular_float_system() {
+    let m = Matrix::from_data(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 1.0], vec![1.0, 1.0, 2.0]]);
+    solve(m, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_solve_tiny_diagonal_system() {
+    let m = Matrix::from_data(vec![vec![1e-20, 0.0], vec![0.0, 1e-20]]);
+    let (_, res) = solve(m, vec![1e-20, 2e-20]);
+    assert_eq!(res, vec![1.0, 2.0]);
+}
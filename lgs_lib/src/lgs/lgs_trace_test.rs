@@ -0,0 +1,90 @@
+use crate::lgs::lgs::{inverse, inverse_traced, solve, solve_traced, EliminationTrace};
+use crate::matrix::matrix::{Matrix, Rational, RowOp};
+use crate::test_util::rationals;
+
+fn r(n: i64) -> Rational {
+    Rational::from(n)
+}
+
+/// Every recorded matrix follows from the previous one by the recorded operation.
+fn assert_replays(trace: &EliminationTrace<Rational>) {
+    let mut m = trace.initial().unwrap().clone();
+    for (op, expected) in trace.steps() {
+        op.apply(&mut m);
+        assert_eq!(&m, expected, "after {}", op);
+    }
+}
+
+#[test]
+fn test_solve_traced_records_every_operation() {
+    let m = rationals(vec![vec![1, 2], vec![3, 4]]);
+    let mut trace = EliminationTrace::new();
+    let (_, x) = solve_traced(m.clone(), vec![r(5), r(6)], &mut trace);
+    assert_eq!(x, solve(m, vec![r(5), r(6)]).1);
+
+    assert_eq!(trace.initial(), Some(&rationals(vec![vec![1, 2, 5], vec![3, 4, 6]])));
+    let ops: Vec<&RowOp<Rational>> = trace.steps().iter().map(|(op, _)| op).collect();
+    assert_eq!(
+        ops,
+        vec![
+            &RowOp::AddMultiple { target: 1, source: 0, factor: r(-3) },
+            &RowOp::AddMultiple { target: 0, source: 1, factor: r(1) },
+            &RowOp::Scale { row: 1, factor: r(-1) / r(2) },
+        ]
+    );
+    assert_replays(&trace);
+    let mut solved = rationals(vec![vec![1, 0, -4], vec![0, 1, 0]]);
+    solved[1][2] = r(9) / r(2);
+    assert_eq!(trace.steps().last().unwrap().1, solved);
+    assert_eq!(x, vec![r(-4), r(9) / r(2)]);
+}
+
+#[test]
+fn test_solve_traced_records_swaps() {
+    let m = rationals(vec![vec![0, 1], vec![2, 1]]);
+    let mut trace = EliminationTrace::new();
+    solve_traced(m, vec![r(3), r(4)], &mut trace);
+    assert_eq!(trace.steps()[0].0, RowOp::Swap(0, 1));
+    assert_replays(&trace);
+}
+
+#[test]
+fn test_inverse_traced() {
+    let m = rationals(vec![vec![2, 1], vec![1, 1]]);
+    let mut trace = EliminationTrace::new();
+    let inv = inverse_traced(m.clone(), &mut trace);
+    assert_eq!(inv, inverse(m.clone()));
+    assert_replays(&trace);
+    assert_eq!(trace.steps().last().unwrap().1, Matrix::identity(2).hstack(&inv));
+}
+
+#[test]
+fn test_trace_as_text() {
+    let mut trace = EliminationTrace::new();
+    solve_traced(rationals(vec![vec![1, 2], vec![3, 4]]), vec![r(5), r(6)], &mut trace);
+    let text = trace.to_text();
+    assert!(text.starts_with("[ 1 2 | 5 ]\n[ 3 4 | 6 ]\n"), "{}", text);
+    assert!(text.contains("R2 ← R2 − 3·R1\n[ 1  2 |  5 ]\n[ 0 -2 | -9 ]\n"), "{}", text);
+    assert!(text.contains("R1 ← R1 + R2\n"), "{}", text);
+    assert!(text.ends_with("R2 ← -1/2·R2\n[ 1 0 |  -4 ]\n[ 0 1 | 9/2 ]\n"), "{}", text);
+}
+
+#[test]
+fn test_trace_as_latex() {
+    let mut trace = EliminationTrace::new();
+    solve_traced(rationals(vec![vec![1, 2], vec![3, 4]]), vec![r(5), r(6)], &mut trace);
+    let latex = trace.to_latex();
+    assert!(latex.starts_with("\\begin{align*}\n& \\left[\\begin{array}{cc|c} 1 & 2 & 5 \\\\ 3 & 4 & 6 \\end{array}\\right]"));
+    assert!(latex.contains("\\xrightarrow{R_{2} \\leftarrow R_{2} - 3 R_{1}}"), "{}", latex);
+    assert!(latex.contains("\\xrightarrow{R_{2} \\leftarrow -\\frac{1}{2} R_{2}}"), "{}", latex);
+    assert!(latex.contains("0 & 1 & \\frac{9}{2}"), "{}", latex);
+    assert!(latex.ends_with("\\end{align*}"));
+}
+
+#[test]
+fn test_row_op_display() {
+    assert_eq!(RowOp::<f64>::Swap(0, 2).to_string(), "R1 ↔ R3");
+    assert_eq!(RowOp::AddMultiple { target: 1, source: 0, factor: -2.0 }.to_string(), "R2 ← R2 − 2·R1");
+    assert_eq!(RowOp::AddMultiple { target: 0, source: 1, factor: 0.5 }.to_string(), "R1 ← R1 + 0.5·R2");
+    assert_eq!(RowOp::Scale { row: 0, factor: 3.0 }.to_string(), "R1 ← 3·R1");
+}
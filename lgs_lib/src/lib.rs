@@ -1,2 +1,6 @@
 pub mod lgs;
-pub mod matrix;
\ No newline at end of file
+pub mod matrix;
+pub mod ode;
+
+#[cfg(test)]
+mod test_util;
@@ -1,67 +1,123 @@
 /// # Matrix
 /// The matrix module contains the implementation and definition of the Matrix struct.
+#[allow(clippy::module_inception)]
 pub mod matrix {
-    
+    use std::fmt::Debug;
+    use std::str::FromStr;
+
+    /// The largest size [`Matrix::det`] computes by cofactor expansion.
+    pub const DET_EXPANSION_LIMIT: usize = 8;
+
     /// Matrix
-    /// Matrix struct, providing a simple interface to interact with matrices of floating point numbers.
+    /// Matrix struct, providing a simple interface to interact with matrices of scalars.
+    /// The element type defaults to `f64`.
     #[derive(Debug, Clone)]
-    pub struct Matrix {
+    pub struct Matrix<T = f64> {
         pub height: usize,
         pub width: usize,
-        data: Vec<Vec<f64>>,
+        data: Vec<Vec<T>>,
+    }
+
+    impl<T: Clone> Matrix<T> {
+        ///# Description:
+        /// Create a new matrix from a vector of vectors.
+        ///
+        /// # Arguments:
+        /// - data: `Vec<Vec<T>>`, The vector of vectors to convert to a matrix.
+        pub fn from_data(data: Vec<Vec<T>>) -> Matrix<T> {
+            Matrix {
+                height: data.len(),
+                width: data[0].len(),
+                data,
+            }
+        }
+
+        pub fn get_data(&self) -> Vec<Vec<T>> {
+            self.data.clone()
+        }
+
+        /// The rows, for routines outside this module that update rows in parallel.
+        pub(crate) fn rows_mut(&mut self) -> &mut [Vec<T>] {
+            &mut self.data
+        }
+
+        ///# Description:
+        /// Swap two rows in the matrix.
+        pub fn swap_rows(&mut self, row: usize, other: usize) {
+            if row >= self.height || other >= self.height {
+                panic!("Row or column index out of bounds");
+            }
+
+            self.data.swap(row, other);
+        }
     }
 
-    impl Matrix {
+    impl<T: Scalar> Matrix<T> {
         ///# Description:
         /// Create a new matrix of the given height and width.
-        /// The values are initialized to 0.0.
+        /// The values are initialized to zero.
         ///
         /// # Arguments:
         /// - height : usize, the height of the matrix
         /// - width : usize, the width of the matrix
-        pub fn new(height: usize, width: usize) -> Matrix {
+        pub fn new(height: usize, width: usize) -> Matrix<T> {
             Matrix {
                 height,
                 width,
-                data: vec![vec![0.0; height]; width],
+                data: vec![vec![T::zero(); width]; height],
+            }
+        }
+
+        ///# Description:
+        /// Create the `n × n` identity matrix.
+        pub fn identity(n: usize) -> Matrix<T> {
+            let mut m = Matrix::new(n, n);
+            for i in 0..n {
+                m[i][i] = T::one();
             }
+            m
         }
 
         ///# Description:
         /// Create a new matrix from the given string.
         /// If the string does not adhere to the legal format of an JSON array
-        /// and/or contains any other values than floating point numbers,
+        /// and/or contains any other values than numbers of the element type,
         /// an empty matrix is returned.
         ///
         /// # Arguments:
         /// - arr_str : &str; The string to convert to a matrix.
-        pub fn from_str(arr_str: &str) -> Matrix {
+        #[allow(clippy::should_implement_trait)]
+        pub fn from_str(arr_str: &str) -> Matrix<T>
+        where
+            T: FromStr,
+            T::Err: Debug,
+        {
             let mut bytes = arr_str.as_bytes();
 
-            if bytes[0] == '[' as u8 && bytes[bytes.len() - 1] == ']' as u8 {
+            if bytes[0] == b'[' && bytes[bytes.len() - 1] == b']' {
                 bytes = &bytes[1..bytes.len() - 1];
             }
 
             // if does not start with '['
             // is invalid array.
             // We return an empty matrix.
-            if arr_str.len() <= 2 || bytes[0] != '[' as u8 {
+            if arr_str.len() <= 2 || bytes[0] != b'[' {
                 return Matrix::new(0, 0);
             }
 
-            let mut data: Vec<Vec<f64>> = vec![];
+            let mut data: Vec<Vec<T>> = vec![];
             let mut j = 0;
             for i in 0..bytes.len() {
-                if '[' as u8 == bytes[i] {
+                if b'[' == bytes[i] {
                     j = i + 1;
                     continue;
                 }
 
-                if ']' as u8 == bytes[i] {
+                if b']' == bytes[i] {
                     let nums = String::from_utf8_lossy(&bytes[j..i]);
                     let nums = nums
                         .split(',')
-                        .map(|s| s.trim().parse::<f64>().unwrap())
+                        .map(|s| s.trim().parse::<T>().unwrap())
                         .collect();
                     data.push(nums)
                 }
@@ -74,33 +130,17 @@ pub mod matrix {
             }
         }
 
-        pub fn get_data(&self) -> Vec<Vec<f64>> {
-            self.data.clone()
-        }
-
-        ///# Description:
-        /// Create a new matrix from a vector of vectors.
-        ///
-        /// # Arguments:
-        /// - data: `Vec<Vec<f64>>`, The vector of vectors to convert to a matrix.
-        pub fn from_data(data: Vec<Vec<f64>>) -> Matrix {
-            Matrix {
-                height: data.len(),
-                width: data[0].len(),
-                data,
-            }
-        }
-
         ///# Description:
         /// Adds a Vector to a row in the matrix.
+        /// To add a multiple of another row use [`Matrix::add_row_multiple`], which does not allocate.
         /// # Panics:
         /// Panics if the row is not of the same length as the matrix width.
-        pub fn add_to_row(&mut self, j: usize, row: Vec<f64>) {
+        pub fn add_to_row(&mut self, j: usize, row: Vec<T>) {
             if row.len() != self.width {
                 panic!("Row length does not match matrix width");
             }
-            for i in 0..self.width {
-                self[j][i] += row[i];
+            for (a, b) in self.data[j].iter_mut().zip(row) {
+                *a = a.clone() + b;
             }
         }
 
@@ -108,13 +148,14 @@ pub mod matrix {
         /// Remove linear dependencies from the matrix.
         /// This returns a new Matrix without linear dependent rows.
         /// The original matrix is unchanged.
-        pub fn remove_linear_dependent_rows(self) -> Matrix {
-            let mut data: Vec<Vec<f64>> = self.data.clone();
+        pub fn remove_linear_dependent_rows(self) -> Matrix<T> {
+            let mut data: Vec<Vec<T>> = self.data.clone();
 
-            for i in (0..self.height).rev().step_by(1) {
+            for i in (0..self.height).rev() {
                 for j in 0..i {
                     if is_a_linear_dependent_row(&self[j], &self[i]) {
                         data.remove(i);
+                        break;
                     }
                 }
             }
@@ -124,23 +165,38 @@ pub mod matrix {
 
         ///# Description:
         /// Calculates the determinant of the matrix.
-        pub fn det(&self) -> f64 {
+        ///
+        /// Up to [`DET_EXPANSION_LIMIT`] rows the cofactor expansion is used,
+        /// which gives the same result as evaluating the textbook formula.
+        /// Larger matrices would need n! products, they use the product of the pivots
+        /// of the LU decomposition with partial pivoting in O(n³) instead.
+        pub fn det(&self) -> T {
             if self.width != self.height {
                 panic!("Cannot calculate determinant of non square matrix");
             }
 
+            if self.height > DET_EXPANSION_LIMIT {
+                return crate::lgs::lgs::lu_decompose(self.clone()).det();
+            }
+
+            // the empty product
+            if self.height == 0 {
+                return T::one();
+            }
+
             if self.height == 1 {
-                return self[0][0];
+                return self[0][0].clone();
             }
 
-            let mut det = 0.0;
-            let i = 0;
+            // the expansion costs n! products, the terms are independent of each other
+            let work = (1..=self.height).fold(1_usize, |acc, k| acc.saturating_mul(k));
+            let terms = parallel::map_range(self.width, work, |j| {
+                self[0][j].clone() * self.submatrix(0, j).det()
+            });
 
-            for j in 0..self.width {
-                // todo: bench and run multithreaded
-                let alpha = (-1_i32).pow((j) as u32) as f64;
-                let a_ij = self[i][j];
-                det += alpha * a_ij * self.submatrix(i, j).det();
+            let mut det = T::zero();
+            for (j, term) in terms.into_iter().enumerate() {
+                det = if j % 2 == 0 { det + term } else { det - term };
             }
             det
         }
@@ -148,19 +204,19 @@ pub mod matrix {
         /// # Description:
         /// create a new matrix that is the submatrix of the current matrix,
         /// beginning at the given row and column.
-        pub fn submatrix(&self, row: usize, col: usize) -> Matrix {
-            let mut data: Vec<Vec<f64>> = vec![];
+        pub fn submatrix(&self, row: usize, col: usize) -> Matrix<T> {
+            let mut data: Vec<Vec<T>> = vec![];
 
             for i in 0..self.height {
                 if i == row {
                     continue;
                 }
-                let mut row: Vec<f64> = vec![];
+                let mut row: Vec<T> = vec![];
                 for j in 0..self.height {
                     if j == col {
                         continue;
                     }
-                    row.push(self[i][j]);
+                    row.push(self[i][j].clone());
                 }
                 data.push(row);
             }
@@ -168,26 +224,66 @@ pub mod matrix {
             Matrix::from_data(data)
         }
 
-        ///# Description:
-        /// Swap two rows in the matrix.
-        pub fn swap_rows(&mut self, row: usize, other: usize) {
-            if row >= self.height || other >= self.height {
-                panic!("Row or column index out of bounds");
-            }
-
-            self.data.swap(row, other);
+        /// # Description:
+        /// Create a new matrix that is the transposed of the current matrix.
+        pub fn transpose(&self) -> Matrix<T> {
+            let mut tmp = Matrix::new(self.width, self.height);
+            parallel::for_each_row(&mut tmp.data, self.height * self.width, |j, row| {
+                for (i, a) in row.iter_mut().enumerate() {
+                    *a = self[i][j].clone();
+                }
+            });
+            tmp
         }
 
         /// # Description:
-        /// Create a new matrix that is the transposed of the current matrix.
-        pub fn transpose(&self) -> Matrix {
+        /// Create the conjugate transpose (adjoint) `A^H` of the matrix.
+        /// For real matrices this is the same as the transpose.
+        pub fn adjoint(&self) -> Matrix<T> {
             let mut tmp = Matrix::new(self.width, self.height);
+            parallel::for_each_row(&mut tmp.data, self.height * self.width, |j, row| {
+                for (i, a) in row.iter_mut().enumerate() {
+                    *a = self[i][j].conj();
+                }
+            });
+            tmp
+        }
+
+        /// # Description:
+        /// Check if the matrix is Hermitian, i.e. equal to its adjoint `A = A^H`,
+        /// within the tolerance of `T::epsilon()`.
+        /// For real matrices this checks for symmetry.
+        pub fn is_hermitian(&self) -> bool {
+            if self.width != self.height {
+                return false;
+            }
             for i in 0..self.height {
-                for j in 0..self.width {
-                    tmp[j][i] = self[i][j];
+                for j in i..self.width {
+                    if !(self[i][j].clone() - self[j][i].conj()).is_zero() {
+                        return false;
+                    }
                 }
             }
-            tmp
+            true
+        }
+
+        /// # Description:
+        /// Check if the matrix is unitary, i.e. `A^H·A = I`,
+        /// with a tolerance of `tol` for every entry.
+        pub fn is_unitary(&self, tol: f64) -> bool {
+            if self.width != self.height {
+                return false;
+            }
+            let p = self.adjoint() * self;
+            for i in 0..p.height {
+                for j in 0..p.width {
+                    let expected = if i == j { T::one() } else { T::zero() };
+                    if (p[i][j].clone() - expected).abs() > tol {
+                        return false;
+                    }
+                }
+            }
+            true
         }
     }
 
@@ -198,15 +294,15 @@ pub mod matrix {
     /// multiplied by a factor n where n is an element of R.
     ///
     /// e.g. [1, 1, 1] and [2,2,2] where [2, 2, 2] is linear dependent of [1, 1, 1].
-    pub(crate) fn is_a_linear_dependent_row(fst: &Vec<f64>, snd: &Vec<f64>) -> bool {
-        if fst.is_empty() || snd.is_empty() {
+    pub(crate) fn is_a_linear_dependent_row<T: Scalar>(fst: &[T], snd: &[T]) -> bool {
+        let Some(k) = fst.iter().position(|x| !x.is_zero()) else {
             return false;
-        }
+        };
 
-        let a: f64 = snd[0] / fst[0];
+        let a: T = snd[k].clone() / fst[k].clone();
 
         for i in 0..fst.len() {
-            if snd[i] - fst[i] * a != 0.0 {
+            if !(snd[i].clone() - fst[i].clone() * a.clone()).is_zero() {
                 return false;
             }
         }
@@ -214,15 +310,76 @@ pub mod matrix {
         true
     }
 
+    pub mod banded;
+    pub mod bareiss;
+    pub mod bigint;
+    pub mod block;
+    pub mod circulant;
+    pub mod complex;
+    pub mod diagonal;
+    pub mod echelon;
+    pub mod fft;
+    pub mod gemm;
+    pub mod gf;
+    pub mod gf2;
+    pub mod integer;
+    pub mod minors;
+    pub mod normal_form;
     pub mod ops;
+    pub mod orthogonal;
+    pub mod parallel;
+    pub mod polynomial;
+    pub mod products;
+    pub mod rational;
+    pub mod row_op;
+    pub mod scalar;
+    pub mod smatrix;
+    pub mod strassen;
+    pub mod symmetric;
+    pub mod toeplitz;
+    pub mod triangular;
+    pub mod vector;
+
+    pub use self::banded::BandedMatrix;
+    pub use self::bigint::BigInt;
+    pub use self::circulant::CirculantMatrix;
+    pub use self::complex::Complex;
+    pub use self::diagonal::DiagonalMatrix;
+    pub use self::gf::Gf;
+    pub use self::gf2::Gf2Matrix;
+    pub use self::integer::Integer;
+    pub use self::rational::Rational;
+    pub use self::row_op::{RowOp, RowOpLog};
+    pub use self::scalar::{ExactScalar, Scalar};
+    pub use self::smatrix::{SMatrix, SMatrix2, SMatrix3, SMatrix4};
+    pub use self::symmetric::SymmetricMatrix;
+    pub use self::toeplitz::ToeplitzMatrix;
+    pub use self::triangular::{LowerTriangular, UpperTriangular};
+    pub use self::vector::Vector;
 }
 
 #[cfg(test)]
-#[feature(test)]
 mod test {
+    mod matrix_bareiss_test;
+    mod matrix_block_test;
     mod matrix_create_test;
     mod matrix_det_test;
+    mod matrix_gemm_test;
+    mod matrix_gf_test;
     mod matrix_linear_dependencies;
+    mod matrix_minors_test;
+    mod matrix_normal_form_test;
     mod matrix_ops_test;
+    mod matrix_orthogonal_test;
+    mod matrix_parallel_test;
+    mod matrix_polynomial_test;
+    mod matrix_products_test;
+    mod matrix_rational_test;
+    mod matrix_row_op_test;
+    mod matrix_scalar_test;
+    mod matrix_smatrix_test;
+    mod matrix_structured_test;
     mod matrix_sub_test;
+    mod matrix_toeplitz_test;
+    mod matrix_vector_test;
 }
@@ -0,0 +1,133 @@
+use super::{Matrix, Scalar, Vector};
+
+/// # Banded matrix
+/// A square matrix whose non-zero entries lie within `kl` diagonals below
+/// and `ku` diagonals above the main diagonal.
+///
+/// Every row stores its `kl + ku + 1` band entries, entry `(i, j)` is at position `j - i + kl`.
+/// Positions outside the matrix at the top and bottom rows stay zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<T = f64> {
+    n: usize,
+    kl: usize,
+    ku: usize,
+    data: Vec<Vec<T>>,
+}
+
+impl<T: Scalar> BandedMatrix<T> {
+    ///# Description:
+    /// Create a zero banded matrix.
+    ///
+    /// # Arguments:
+    /// - n: usize, the number of rows and columns
+    /// - kl: usize, the number of sub diagonals
+    /// - ku: usize, the number of super diagonals
+    pub fn new(n: usize, kl: usize, ku: usize) -> BandedMatrix<T> {
+        BandedMatrix {
+            n,
+            kl,
+            ku,
+            data: vec![vec![T::zero(); kl + ku + 1]; n],
+        }
+    }
+
+    ///# Description:
+    /// Take the band of a square matrix.
+    /// Returns `None` if the matrix is not square or has non-zero entries outside the band.
+    pub fn from_matrix(m: &Matrix<T>, kl: usize, ku: usize) -> Option<BandedMatrix<T>> {
+        if m.width != m.height {
+            return None;
+        }
+        let mut b = BandedMatrix::new(m.height, kl, ku);
+        for i in 0..m.height {
+            for j in 0..m.width {
+                if b.in_band(i, j) {
+                    b.data[i][j + kl - i] = m[i][j].clone();
+                } else if !m[i][j].is_zero() {
+                    return None;
+                }
+            }
+        }
+        Some(b)
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// The number of sub diagonals.
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    /// The number of super diagonals.
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        j + self.kl >= i && j <= i + self.ku
+    }
+
+    /// The entry in row `i` and column `j`, zero outside the band.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= self.n || j >= self.n {
+            panic!("Row or column index out of bounds");
+        }
+        if !self.in_band(i, j) {
+            return T::zero();
+        }
+        self.data[i][j + self.kl - i].clone()
+    }
+
+    ///# Description:
+    /// Set the entry in row `i` and column `j`.
+    ///
+    /// # Panics:
+    /// Panics if the position is outside the matrix or the band.
+    pub fn set(&mut self, i: usize, j: usize, v: T) {
+        if i >= self.n || j >= self.n {
+            panic!("Row or column index out of bounds");
+        }
+        if !self.in_band(i, j) {
+            panic!("Position is outside the band");
+        }
+        self.data[i][j + self.kl - i] = v;
+    }
+
+    /// The columns of row `i` that lie within the band.
+    fn columns(&self, i: usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n)
+    }
+
+    ///# Description:
+    /// The product `A·x` in O(n·(kl + ku)).
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        (0..self.n)
+            .map(|i| {
+                self.columns(i).fold(T::zero(), |acc, j| {
+                    acc + self.data[i][j + self.kl - i].clone() * x[j].clone()
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: Scalar> From<BandedMatrix<T>> for Matrix<T> {
+    fn from(b: BandedMatrix<T>) -> Self {
+        let mut m = Matrix::new(b.n, b.n);
+        for i in 0..b.n {
+            for j in b.columns(i) {
+                m[i][j] = b.data[i][j + b.kl - i].clone();
+            }
+        }
+        m
+    }
+}
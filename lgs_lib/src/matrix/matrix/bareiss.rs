@@ -0,0 +1,83 @@
+use super::{Integer, Matrix};
+
+impl<T: Integer> Matrix<T> {
+    /// # Description:
+    /// Reduce the matrix to row echelon form with the fraction-free Bareiss algorithm.
+    ///
+    /// Every intermediate entry is a minor of the original matrix, so all divisions are exact
+    /// and no rationals are needed. Columns without a pivot are skipped,
+    /// which makes this work for singular and non-square matrices as well.
+    ///
+    /// Returns the echelon form together with the rank,
+    /// or `None` if an intermediate value overflows `T`.
+    pub fn bareiss(&self) -> Option<(Matrix<T>, usize)> {
+        self.bareiss_elimination().map(|(m, rank, _)| (m, rank))
+    }
+
+    /// # Description:
+    /// Calculates the exact determinant with the Bareiss algorithm.
+    /// Returns `None` if an intermediate value overflows `T`.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn bareiss_det(&self) -> Option<T> {
+        if self.width != self.height {
+            panic!("Cannot calculate determinant of non square matrix");
+        }
+        if self.height == 0 {
+            return Some(T::one());
+        }
+
+        let (m, rank, negate) = self.bareiss_elimination()?;
+        if rank < self.height {
+            return Some(T::zero());
+        }
+        let det = m[self.height - 1][self.width - 1].clone();
+        if negate {
+            det.checked_neg()
+        } else {
+            Some(det)
+        }
+    }
+
+    /// # Description:
+    /// Calculates the exact rank with the Bareiss algorithm.
+    /// Returns `None` if an intermediate value overflows `T`.
+    pub fn bareiss_rank(&self) -> Option<usize> {
+        self.bareiss_elimination().map(|(_, rank, _)| rank)
+    }
+
+    /// Returns the echelon form, the rank and whether an odd number of row swaps happened.
+    fn bareiss_elimination(&self) -> Option<(Matrix<T>, usize, bool)> {
+        let mut m = self.clone();
+        let mut prev = T::one();
+        let mut negate = false;
+        let mut r = 0;
+
+        for k in 0..m.width {
+            if r == m.height {
+                break;
+            }
+            let Some(p) = (r..m.height).find(|&p| !m[p][k].is_zero()) else {
+                continue;
+            };
+            if p != r {
+                m.swap_rows(p, r);
+                negate = !negate;
+            }
+
+            for i in (r + 1)..m.height {
+                for j in (k + 1)..m.width {
+                    let a = m[i][j].checked_mul(&m[r][k])?;
+                    let b = m[i][k].checked_mul(&m[r][j])?;
+                    m[i][j] = a.checked_sub(&b)?.checked_exact_div(&prev)?;
+                }
+                m[i][k] = T::zero();
+            }
+            prev = m[r][k].clone();
+            r += 1;
+        }
+
+        Some((m, r, negate))
+    }
+}
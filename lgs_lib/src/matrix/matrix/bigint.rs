@@ -0,0 +1,482 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+/// # BigInt
+/// An arbitrary precision signed integer.
+///
+/// The magnitude is stored as little endian base 2^32 limbs without trailing zeros,
+/// so zero is represented by an empty magnitude and is never negative.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    mag: Vec<u32>,
+}
+
+/// Error returned when a string cannot be parsed as a [`BigInt`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid integer literal")
+    }
+}
+
+impl BigInt {
+    ///# Description:
+    /// The integer zero.
+    pub fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    ///# Description:
+    /// The integer one.
+    pub fn one() -> BigInt {
+        BigInt::from(1_i64)
+    }
+
+    fn from_parts(negative: bool, mut mag: Vec<u32>) -> BigInt {
+        while mag.last() == Some(&0) {
+            mag.pop();
+        }
+        let negative = negative && !mag.is_empty();
+        BigInt { negative, mag }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.mag == [1]
+    }
+
+    ///# Description:
+    /// The absolute value of the integer.
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.mag.clone())
+    }
+
+    ///# Description:
+    /// -1, 0 or 1 depending on the sign of the integer.
+    pub fn signum(&self) -> i32 {
+        match (self.is_zero(), self.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        }
+    }
+
+    ///# Description:
+    /// The number of bits needed to represent the magnitude.
+    pub fn bits(&self) -> u64 {
+        match self.mag.last() {
+            None => 0,
+            Some(top) => self.mag.len() as u64 * 32 - top.leading_zeros() as u64,
+        }
+    }
+
+    ///# Description:
+    /// Truncated division, returning quotient and remainder.
+    /// The remainder has the sign of the dividend, as for the primitive integers.
+    ///
+    /// # Panics:
+    /// Panics if the divisor is zero.
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        if other.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        let (q, r) = div_rem_mag(&self.mag, &other.mag);
+        (
+            BigInt::from_parts(self.negative != other.negative, q),
+            BigInt::from_parts(self.negative, r),
+        )
+    }
+
+    ///# Description:
+    /// The greatest common divisor of two integers, which is always non-negative.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    ///# Description:
+    /// Convert to an `i64`, if the value fits.
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.mag.len() > 2 {
+            return None;
+        }
+        let m = self.mag.iter().rev().fold(0_u64, |acc, &l| (acc << 32) | l as u64);
+        if self.negative {
+            if m <= i64::MAX as u64 + 1 {
+                Some((m as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(m).ok()
+        }
+    }
+
+    ///# Description:
+    /// Convert to the nearest `f64`, overflowing to infinity for very large values.
+    pub fn to_f64(&self) -> f64 {
+        let (m, e) = self.to_f64_exp();
+        m * 2_f64.powi(e)
+    }
+
+    /// Split into `m · 2^e` where `m` is built from the top 64 bits of the magnitude,
+    /// so that huge values can be divided by each other without overflowing.
+    pub(crate) fn to_f64_exp(&self) -> (f64, i32) {
+        let bits = self.bits();
+        let shift = bits.saturating_sub(64);
+        let top = shr_mag(&self.mag, shift);
+        let m = top.iter().rev().fold(0_u64, |acc, &l| (acc << 32) | l as u64) as f64;
+        (if self.negative { -m } else { m }, shift as i32)
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (i, &l) in long.iter().enumerate() {
+        let s = l as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        res.push(s as u32);
+        carry = s >> 32;
+    }
+    if carry > 0 {
+        res.push(carry as u32);
+    }
+    res
+}
+
+/// Subtract the magnitudes `a - b`, where `a >= b`.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0_i64;
+    for (i, &l) in a.iter().enumerate() {
+        let mut d = l as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if d < 0 {
+            d += 1 << 32;
+            borrow = 1;
+        }
+        res.push(d as u32);
+    }
+    res
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![0_u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + res[i + j] as u64 + carry;
+            res[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    res
+}
+
+fn shl_mag(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0_u32;
+    for &l in a {
+        res.push((l << shift) | carry);
+        carry = l >> (32 - shift);
+    }
+    res.push(carry);
+    res
+}
+
+fn shr_mag(a: &[u32], shift: u64) -> Vec<u32> {
+    let limbs = (shift / 32) as usize;
+    let bits = (shift % 32) as u32;
+    if limbs >= a.len() {
+        return vec![];
+    }
+    let a = &a[limbs..];
+    if bits == 0 {
+        return a.to_vec();
+    }
+    (0..a.len())
+        .map(|i| (a[i] >> bits) | a.get(i + 1).map_or(0, |&h| h << (32 - bits)))
+        .collect()
+}
+
+/// Long division of magnitudes (Knuth, The Art of Computer Programming, Vol. 2, Algorithm D).
+fn div_rem_mag(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_mag(u, v) == Ordering::Less {
+        return (vec![], u.to_vec());
+    }
+
+    if v.len() == 1 {
+        let d = v[0] as u64;
+        let mut q = vec![0_u32; u.len()];
+        let mut r = 0_u64;
+        for i in (0..u.len()).rev() {
+            let cur = (r << 32) | u[i] as u64;
+            q[i] = (cur / d) as u32;
+            r = cur % d;
+        }
+        return (q, vec![r as u32]);
+    }
+
+    let n = v.len();
+    let m = u.len() - n;
+    let s = v[n - 1].leading_zeros();
+    let vn = shl_mag(v, s);
+    let mut un = shl_mag(u, s);
+    if un.len() == u.len() {
+        un.push(0);
+    }
+    let mut q = vec![0_u32; m + 1];
+    let base = 1_u64 << 32;
+
+    for j in (0..=m).rev() {
+        let num = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= base || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        let mut k = 0_i64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64;
+            let t = un[i + j] as i64 - k - (p & 0xFFFF_FFFF) as i64;
+            un[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = un[j + n] as i64 - k;
+        un[j + n] = t as u32;
+
+        q[j] = qhat as u32;
+        if t < 0 {
+            q[j] = q[j].wrapping_sub(1);
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let t = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = t as u32;
+                carry = t >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+    }
+
+    (q, shr_mag(&un[..n], s as u64))
+}
+
+impl From<i64> for BigInt {
+    fn from(v: i64) -> Self {
+        let m = v.unsigned_abs();
+        BigInt::from_parts(v < 0, vec![m as u32, (m >> 32) as u32])
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(v: u64) -> Self {
+        BigInt::from_parts(false, vec![v as u32, (v >> 32) as u32])
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(v: i32) -> Self {
+        BigInt::from(v as i64)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: BigInt) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_mag(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: BigInt) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: BigInt) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::from_parts(self.negative != rhs.negative, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: BigInt) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: BigInt) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.mag.clone())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut mag: Vec<u32> = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let scale = 10_u32.pow(chunk.len() as u32);
+            let value = chunk.iter().fold(0_u32, |acc, b| acc * 10 + (b - b'0') as u32);
+            let mut carry = value as u64;
+            for l in mag.iter_mut() {
+                let t = *l as u64 * scale as u64 + carry;
+                *l = t as u32;
+                carry = t >> 32;
+            }
+            if carry > 0 {
+                mag.push(carry as u32);
+            }
+        }
+        Ok(BigInt::from_parts(negative, mag))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut chunks: Vec<u32> = vec![];
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = div_rem_mag(&mag, &[1_000_000_000]);
+            chunks.push(*r.first().unwrap_or(&0));
+            mag = BigInt::from_parts(false, q).mag;
+        }
+        let mut s = chunks.pop().unwrap_or(0).to_string();
+        for c in chunks.iter().rev() {
+            s.push_str(&format!("{:09}", c));
+        }
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
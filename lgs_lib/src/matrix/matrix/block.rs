@@ -0,0 +1,110 @@
+use super::Matrix;
+use std::ops::{Bound, Range, RangeBounds};
+
+impl<T: Clone> Matrix<T> {
+    ///# Description:
+    /// Place `other` to the right of the matrix.
+    ///
+    /// # Panics:
+    /// Panics if the heights differ.
+    pub fn hstack(&self, other: &Matrix<T>) -> Matrix<T> {
+        Matrix::block(&[&[self, other]])
+    }
+
+    ///# Description:
+    /// Place `other` below the matrix.
+    ///
+    /// # Panics:
+    /// Panics if the widths differ.
+    pub fn vstack(&self, other: &Matrix<T>) -> Matrix<T> {
+        Matrix::block(&[&[self], &[other]])
+    }
+
+    ///# Description:
+    /// Assemble a matrix from a grid of blocks, given row by row.
+    ///
+    /// e.g. the KKT matrix `[[H, Aᵀ], [A, 0]]` is `Matrix::block(&[&[&h, &at], &[&a, &zero]])`.
+    ///
+    /// # Panics:
+    /// Panics if the blocks in a row differ in height,
+    /// or the block rows add up to different widths.
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Matrix<T> {
+        let mut data: Vec<Vec<T>> = vec![];
+        let mut width = None;
+
+        for row in blocks {
+            let height = row.first().map_or(0, |b| b.height);
+            if row.iter().any(|b| b.height != height) {
+                panic!("Blocks in a row must have the same height");
+            }
+            let row_width: usize = row.iter().map(|b| b.width).sum();
+            if *width.get_or_insert(row_width) != row_width {
+                panic!("Block rows must have the same width");
+            }
+
+            for i in 0..height {
+                let mut r = Vec::with_capacity(row_width);
+                for b in row.iter() {
+                    r.extend_from_slice(&b.data[i]);
+                }
+                data.push(r);
+            }
+        }
+
+        Matrix {
+            height: data.len(),
+            width: width.unwrap_or(0),
+            data,
+        }
+    }
+
+    ///# Description:
+    /// Copy out the block of the given rows and columns.
+    ///
+    /// # Arguments:
+    /// - rows: any range of row indices, e.g. `1..3` or `..`
+    /// - cols: any range of column indices
+    ///
+    /// # Panics:
+    /// Panics if a range reaches outside the matrix.
+    pub fn slice(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> Matrix<T> {
+        let rows = resolve(rows, self.height);
+        let cols = resolve(cols, self.width);
+        Matrix {
+            height: rows.len(),
+            width: cols.len(),
+            data: self.data[rows].iter().map(|r| r[cols.clone()].to_vec()).collect(),
+        }
+    }
+
+    ///# Description:
+    /// Overwrite the block starting at row `row` and column `col` with `block`.
+    ///
+    /// # Panics:
+    /// Panics if the block does not fit into the matrix at that position.
+    pub fn set_block(&mut self, row: usize, col: usize, block: &Matrix<T>) {
+        if row + block.height > self.height || col + block.width > self.width {
+            panic!("Block does not fit into the matrix");
+        }
+        for (dst, src) in self.data[row..row + block.height].iter_mut().zip(&block.data) {
+            dst[col..col + block.width].clone_from_slice(src);
+        }
+    }
+}
+
+fn resolve(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        panic!("Row or column index out of bounds");
+    }
+    start..end
+}
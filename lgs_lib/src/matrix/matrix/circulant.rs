@@ -0,0 +1,113 @@
+use super::fft::{fft, ifft, FftScalar};
+use super::{Complex, Matrix, Vector};
+
+/// # Circulant matrix
+/// A Toeplitz matrix whose rows are cyclic shifts of each other, `a_ij = c((i - j) mod n)`.
+/// It is stored by its first column.
+///
+/// Every circulant matrix is diagonalized by the discrete Fourier transform,
+/// its eigenvalues are the transform of the first column.
+/// Products and solves therefore take O(n log n).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CirculantMatrix<T = f64> {
+    col: Vec<T>,
+}
+
+impl<T: FftScalar> CirculantMatrix<T> {
+    ///# Description:
+    /// Create a circulant matrix from its first column.
+    pub fn new(first_col: Vec<T>) -> CirculantMatrix<T> {
+        CirculantMatrix { col: first_col }
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.col.len()
+    }
+
+    pub fn first_col(&self) -> &[T] {
+        &self.col
+    }
+
+    /// The entry in row `i` and column `j`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let n = self.size();
+        if i >= n || j >= n {
+            panic!("Row or column index out of bounds");
+        }
+        self.col[(i + n - j) % n].clone()
+    }
+
+    ///# Description:
+    /// The eigenvalues, the discrete Fourier transform of the first column.
+    /// Eigenvalue `k` belongs to the eigenvector `(e^(2πi·jk/n))_j`.
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        transformed(&self.col)
+    }
+
+    /// The determinant, the product of the eigenvalues.
+    pub fn det(&self) -> T {
+        T::from_complex(self.eigenvalues().into_iter().fold(Complex::from(1.0), |acc, l| acc * l))
+    }
+
+    ///# Description:
+    /// The product `C·x` as a cyclic convolution in O(n log n).
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.size() {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut y = transformed(x);
+        for (y, l) in y.iter_mut().zip(self.eigenvalues()) {
+            *y = *y * l;
+        }
+        ifft(&mut y);
+        y.into_iter().map(T::from_complex).collect()
+    }
+
+    ///# Description:
+    /// Solve `C·x = b` by dividing by the eigenvalues in O(n log n).
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match or the matrix is singular,
+    /// i.e. an eigenvalue is zero relative to the largest one.
+    pub fn solve(&self, b: &[T]) -> Vector<T> {
+        if b.len() != self.size() {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let eigenvalues = self.eigenvalues();
+        let largest = eigenvalues.iter().map(|l| l.norm_sqr().sqrt()).fold(0.0, f64::max);
+        let tol = largest * f64::EPSILON * self.size() as f64;
+        if eigenvalues.iter().any(|l| l.norm_sqr().sqrt() <= tol) {
+            panic!("Matrix is singular");
+        }
+
+        let mut x = transformed(b);
+        for (x, l) in x.iter_mut().zip(eigenvalues) {
+            *x = *x / l;
+        }
+        ifft(&mut x);
+        x.into_iter().map(T::from_complex).collect()
+    }
+}
+
+fn transformed<T: FftScalar>(x: &[T]) -> Vec<Complex> {
+    let mut data: Vec<Complex> = x.iter().map(|x| x.to_complex()).collect();
+    fft(&mut data);
+    data
+}
+
+impl<T: FftScalar> From<CirculantMatrix<T>> for Matrix<T> {
+    fn from(c: CirculantMatrix<T>) -> Self {
+        let n = c.size();
+        let mut m = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                m[i][j] = c.get(i, j);
+            }
+        }
+        m
+    }
+}
@@ -0,0 +1,172 @@
+use super::Scalar;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// # Complex
+/// A complex number `re + im·i` with `f64` components.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    ///# Description:
+    /// Create a new complex number from its real and imaginary part.
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    ///# Description:
+    /// The imaginary unit `i`.
+    pub fn i() -> Complex {
+        Complex::new(0.0, 1.0)
+    }
+
+    ///# Description:
+    /// Create a complex number from its modulus `r` and argument `theta` in radians.
+    pub fn from_polar(r: f64, theta: f64) -> Complex {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    ///# Description:
+    /// The complex conjugate `re - im·i`.
+    pub fn conj(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    ///# Description:
+    /// The argument (phase angle) in radians, in the range `(-π, π]`.
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    ///# Description:
+    /// The squared modulus `re² + im²`.
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Self::Output {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Self::Output {
+        let d = rhs.norm_sqr();
+        let n = self * rhs.conj();
+        Complex::new(n.re / d, n.im / d)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl Scalar for Complex {
+    fn zero() -> Self {
+        Complex::new(0.0, 0.0)
+    }
+
+    fn one() -> Self {
+        Complex::new(1.0, 0.0)
+    }
+
+    fn from_f64(v: f64) -> Self {
+        Complex::from(v)
+    }
+
+    fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    fn epsilon() -> f64 {
+        f64::EPSILON
+    }
+
+    fn conj(&self) -> Self {
+        Complex::conj(self)
+    }
+}
+
+/// Error returned when a string cannot be parsed as a [`Complex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError;
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid complex literal")
+    }
+}
+
+/// Parses `a`, `bi`, `a+bi` and `a-bi`, where `j` may be used instead of `i`.
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let Some(body) = s.strip_suffix(['i', 'j']) else {
+            return s.parse::<f64>().map(Complex::from).map_err(|_| ParseComplexError);
+        };
+
+        // split before the last sign that is not the leading one or part of an exponent
+        let bytes = body.as_bytes();
+        let split = (1..bytes.len())
+            .rev()
+            .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+        let (re, im) = match split {
+            Some(k) => (&body[..k], &body[k..]),
+            None => ("0", body),
+        };
+        let im = match im {
+            "" | "+" => "1",
+            "-" => "-1",
+            _ => im,
+        };
+        let re: f64 = re.parse().map_err(|_| ParseComplexError)?;
+        let im: f64 = im.parse().map_err(|_| ParseComplexError)?;
+        Ok(Complex::new(re, im))
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Matrix, Scalar, Vector};
+
+/// # Diagonal matrix
+/// A square matrix that is zero off the main diagonal, stored as the diagonal only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagonalMatrix<T = f64> {
+    diag: Vec<T>,
+}
+
+impl<T: Scalar> DiagonalMatrix<T> {
+    ///# Description:
+    /// Create a diagonal matrix with the given entries on the diagonal.
+    pub fn new(diag: Vec<T>) -> DiagonalMatrix<T> {
+        DiagonalMatrix { diag }
+    }
+
+    ///# Description:
+    /// The `n × n` identity matrix.
+    pub fn identity(n: usize) -> DiagonalMatrix<T> {
+        DiagonalMatrix {
+            diag: vec![T::one(); n],
+        }
+    }
+
+    ///# Description:
+    /// Take the diagonal of a square matrix.
+    /// Returns `None` if the matrix is not square or has non-zero entries off the diagonal.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<DiagonalMatrix<T>> {
+        if m.width != m.height {
+            return None;
+        }
+        for i in 0..m.height {
+            if (0..m.width).any(|j| i != j && !m[i][j].is_zero()) {
+                return None;
+            }
+        }
+        Some(DiagonalMatrix::new((0..m.height).map(|i| m[i][i].clone()).collect()))
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    pub fn diagonal(&self) -> &[T] {
+        &self.diag
+    }
+
+    /// The product of the diagonal.
+    pub fn det(&self) -> T {
+        self.diag.iter().fold(T::one(), |acc, d| acc * d.clone())
+    }
+
+    /// The inverse, the reciprocals of the diagonal.
+    /// Returns `None` if an entry on the diagonal is zero.
+    pub fn inverse(&self) -> Option<DiagonalMatrix<T>> {
+        if self.diag.iter().any(|d| d.is_zero()) {
+            return None;
+        }
+        Some(DiagonalMatrix::new(
+            self.diag.iter().map(|d| T::one() / d.clone()).collect(),
+        ))
+    }
+
+    ///# Description:
+    /// The product `D·x` in O(n).
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.size() {
+            panic!("Matrix and vector lengths do not match");
+        }
+        self.diag
+            .iter()
+            .zip(x)
+            .map(|(d, x)| d.clone() * x.clone())
+            .collect()
+    }
+
+    ///# Description:
+    /// Solve `D·x = b` in O(n).
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match or the matrix is singular.
+    pub fn solve(&self, b: &[T]) -> Vector<T> {
+        if b.len() != self.size() {
+            panic!("Matrix and vector lengths do not match");
+        }
+        if self.diag.iter().any(|d| d.is_zero()) {
+            panic!("Matrix is singular");
+        }
+        self.diag
+            .iter()
+            .zip(b)
+            .map(|(d, b)| b.clone() / d.clone())
+            .collect()
+    }
+}
+
+impl<T: Scalar> From<DiagonalMatrix<T>> for Matrix<T> {
+    fn from(d: DiagonalMatrix<T>) -> Self {
+        let mut m = Matrix::new(d.size(), d.size());
+        for (i, v) in d.diag.into_iter().enumerate() {
+            m[i][i] = v;
+        }
+        m
+    }
+}
@@ -0,0 +1,119 @@
+use super::parallel::for_each_row;
+use super::{Matrix, Scalar};
+
+impl<T: Scalar> Matrix<T> {
+    /// # Description:
+    /// Create the reduced row echelon form of the matrix with Gauss-Jordan elimination.
+    ///
+    /// Every pivot is 1 and is the only non-zero entry in its column.
+    /// Pivots are chosen by largest absolute value. Candidates up to `max(m, n)·ε·‖A‖∞`
+    /// are treated as zero, with `ε = T::epsilon()`, so the result does not depend on the scale
+    /// of the matrix. For exact scalars `ε` is zero and only exact zeros are rejected.
+    ///
+    /// Returns the reduced matrix together with the pivot column of every non-zero row.
+    pub fn rref(&self) -> (Matrix<T>, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = vec![];
+        let mut r = 0;
+        let tol = self.zero_tolerance();
+
+        for c in 0..m.width {
+            if r == m.height {
+                break;
+            }
+            let mut p = r;
+            for i in r..m.height {
+                // the absolute value of exact scalars may round to 0 or overflow
+                if m[i][c].abs() > m[p][c].abs() || (m[p][c].is_zero() && !m[i][c].is_zero()) {
+                    p = i;
+                }
+            }
+            if is_negligible(&m[p][c], tol) {
+                for i in r..m.height {
+                    m[i][c] = T::zero();
+                }
+                continue;
+            }
+            m.swap_rows(r, p);
+
+            let inv = T::one() / m[r][c].clone();
+            for j in c..m.width {
+                m[r][j] = m[r][j].clone() * inv.clone();
+            }
+            m[r][c] = T::one();
+
+            let pivot = m[r].clone();
+            let work = m.height * (m.width - c);
+            for_each_row(&mut m.data, work, |i, row| {
+                if i == r {
+                    return;
+                }
+                if row[c] != T::zero() {
+                    let factor = row[c].clone();
+                    for j in c..row.len() {
+                        row[j] = row[j].clone() - factor.clone() * pivot[j].clone();
+                    }
+                }
+                row[c] = T::zero();
+            });
+
+            pivots.push(c);
+            r += 1;
+        }
+
+        (m, pivots)
+    }
+
+    /// # Description:
+    /// The rank of the matrix, the number of linear independent rows,
+    /// with the relative tolerance of [`Matrix::rref`].
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// # Description:
+    /// A basis of the null space, all vectors `x` with `A·x = 0`.
+    ///
+    /// There is one basis vector for every column without a pivot in the reduced row echelon form,
+    /// so the result is empty if the columns are linear independent.
+    pub fn null_space(&self) -> Vec<Vec<T>> {
+        let (m, pivots) = self.rref();
+        let mut basis = vec![];
+
+        for free in (0..self.width).filter(|c| !pivots.contains(c)) {
+            let mut x = vec![T::zero(); self.width];
+            x[free] = T::one();
+            for (row, &p) in pivots.iter().enumerate() {
+                x[p] = -m[row][free].clone();
+            }
+            basis.push(x);
+        }
+        basis
+    }
+
+    /// The tolerance `max(m, n)·ε·‖A‖∞` below which pivots count as zero.
+    /// It is zero for exact scalars, whose norm may overflow to infinity.
+    pub(crate) fn zero_tolerance(&self) -> f64 {
+        if T::epsilon() == 0.0 {
+            return 0.0;
+        }
+        T::epsilon() * self.height.max(self.width) as f64 * self.norm_inf()
+    }
+
+    /// The maximum absolute row sum.
+    pub(crate) fn norm_inf(&self) -> f64 {
+        (0..self.height)
+            .map(|i| self[i].iter().map(|x| x.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Whether `x` counts as zero with a tolerance of [`Matrix::zero_tolerance`].
+/// A zero tolerance only accepts exact zeros.
+pub(crate) fn is_negligible<T: Scalar>(x: &T, tol: f64) -> bool {
+    if tol == 0.0 {
+        x.is_zero()
+    } else {
+        x.abs() <= tol
+    }
+}
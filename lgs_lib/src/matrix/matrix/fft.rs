@@ -0,0 +1,138 @@
+use super::{Complex, Scalar};
+use std::f64::consts::PI;
+
+/// # FftScalar
+/// Scalars that can be moved into the complex plane and back,
+/// so that they can be transformed with the [`fft`].
+pub trait FftScalar: Scalar {
+    fn to_complex(&self) -> Complex;
+
+    /// Convert back, real types drop the imaginary part.
+    fn from_complex(c: Complex) -> Self;
+}
+
+impl FftScalar for f64 {
+    fn to_complex(&self) -> Complex {
+        Complex::from(*self)
+    }
+
+    fn from_complex(c: Complex) -> Self {
+        c.re
+    }
+}
+
+impl FftScalar for f32 {
+    fn to_complex(&self) -> Complex {
+        Complex::from(*self as f64)
+    }
+
+    fn from_complex(c: Complex) -> Self {
+        c.re as f32
+    }
+}
+
+impl FftScalar for Complex {
+    fn to_complex(&self) -> Complex {
+        *self
+    }
+
+    fn from_complex(c: Complex) -> Self {
+        c
+    }
+}
+
+///# Description:
+/// The discrete Fourier transform `X_k = Σ x_j·e^(-2πi·jk/n)`, computed in place in O(n log n).
+/// Lengths that are not a power of two are handled with Bluestein's algorithm.
+pub fn fft(data: &mut [Complex]) {
+    transform(data, false);
+}
+
+///# Description:
+/// The inverse of [`fft`], including the scaling by `1/n`.
+pub fn ifft(data: &mut [Complex]) {
+    transform(data, true);
+    let scale = 1.0 / data.len() as f64;
+    for x in data.iter_mut() {
+        *x = Complex::new(x.re * scale, x.im * scale);
+    }
+}
+
+fn transform(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+}
+
+/// Iterative Cooley-Tukey for power of two lengths.
+fn radix2(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        // twiddles are computed directly instead of by repeated multiplication, which accumulates error
+        let twiddles: Vec<Complex> = (0..len / 2)
+            .map(|k| Complex::from_polar(1.0, sign * 2.0 * PI * k as f64 / len as f64))
+            .collect();
+        for chunk in data.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((a, b), w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let t = *b * *w;
+                *b = *a - t;
+                *a = *a + t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bluestein's algorithm: rewrite the transform as a convolution of power of two length.
+fn bluestein(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // chirp w_k = e^(∓πi·k²/n), k² is reduced modulo 2n to keep the angle small
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            Complex::from_polar(1.0, sign * PI * k2 as f64 / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex::default(); m];
+    for (a, (x, w)) in a.iter_mut().zip(data.iter().zip(&chirp)) {
+        *a = *x * *w;
+    }
+    let mut b = vec![Complex::default(); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = *a * *b;
+    }
+    ifft(&mut a);
+
+    for (x, (c, w)) in data.iter_mut().zip(a.iter().zip(&chirp)) {
+        *x = *c * *w;
+    }
+}
@@ -0,0 +1,74 @@
+use super::parallel::for_each_chunk;
+use super::{Matrix, Scalar};
+
+/// Edge length of the square tiles the product is split into.
+/// A tile of `f64` fills 32 KiB, so one tile of `B` stays in the L1/L2 cache while it is reused.
+const BLOCK: usize = 64;
+
+/// Products with fewer multiply-adds than this are not worth distributing over threads.
+const PARALLEL_THRESHOLD: usize = BLOCK * BLOCK * BLOCK;
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The textbook i-j-k triple loop.
+    /// Kept as the reference the blocked product is tested and benchmarked against.
+    ///
+    /// # Panics:
+    /// Panics if the width of `self` does not match the height of `m`.
+    pub fn mul_naive(&self, m: &Matrix<T>) -> Matrix<T> {
+        check_mul_dimensions(self, m);
+        let mut tmp: Matrix<T> = Matrix::new(self.height, m.width);
+
+        for i in 0..self.height {
+            for j in 0..m.width {
+                for k in 0..self.width {
+                    tmp[i][j] = tmp[i][j].clone() + self[i][k].clone() * m[k][j].clone();
+                }
+            }
+        }
+        tmp
+    }
+
+    ///# Description:
+    /// Cache-blocked matrix product, used by the `*` operator.
+    ///
+    /// The product is computed tile by tile in i-k-j order, so the innermost loop
+    /// runs over contiguous rows and is handed to [`Scalar::axpy`].
+    /// With the `parallel` feature, large products are split by rows over the rayon thread pool.
+    /// Every entry is accumulated in the same order as in [`Matrix::mul_naive`],
+    /// so all variants return identical results.
+    ///
+    /// # Panics:
+    /// Panics if the width of `self` does not match the height of `m`.
+    pub fn mul_blocked(&self, m: &Matrix<T>) -> Matrix<T> {
+        check_mul_dimensions(self, m);
+        let mut tmp: Matrix<T> = Matrix::new(self.height, m.width);
+        let work = self.height * self.width * m.width;
+        for_each_chunk(&mut tmp.data, BLOCK, work, PARALLEL_THRESHOLD, |first, rows| {
+            multiply_rows(self, m, first, rows)
+        });
+        tmp
+    }
+}
+
+fn check_mul_dimensions<T>(a: &Matrix<T>, b: &Matrix<T>) {
+    if a.width != b.height {
+        panic!("Matrix dimensions do not match");
+    }
+}
+
+/// Accumulate `a·b` into `rows`, which are the rows of the result starting at `first_row`.
+fn multiply_rows<T: Scalar>(a: &Matrix<T>, b: &Matrix<T>, first_row: usize, rows: &mut [Vec<T>]) {
+    for kk in (0..a.width).step_by(BLOCK) {
+        let k_end = (kk + BLOCK).min(a.width);
+        for jj in (0..b.width).step_by(BLOCK) {
+            let j_end = (jj + BLOCK).min(b.width);
+            for (r, row) in rows.iter_mut().enumerate() {
+                let a_row = &a.data[first_row + r][kk..k_end];
+                for (a_ik, b_row) in a_row.iter().zip(&b.data[kk..k_end]) {
+                    T::axpy(&mut row[jj..j_end], a_ik, &b_row[jj..j_end]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::{ExactScalar, Scalar};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// # Gf
+/// An element of the prime field GF(P), the integers modulo the prime `P`.
+///
+/// `P` has to be prime for division to be defined, which is not checked.
+/// Values are always kept reduced to `0..P`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf<const P: u64>(u64);
+
+impl<const P: u64> Gf<P> {
+    ///# Description:
+    /// Create a new field element from an integer, reducing it modulo `P`.
+    pub fn new(v: i64) -> Gf<P> {
+        Gf((v as i128).rem_euclid(P as i128) as u64)
+    }
+
+    ///# Description:
+    /// The representative of the element in `0..P`.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    ///# Description:
+    /// Raise the element to the power `e` by repeated squaring.
+    pub fn pow(self, mut e: u64) -> Gf<P> {
+        let mut base = self;
+        let mut acc = Gf(1 % P);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        acc
+    }
+
+    ///# Description:
+    /// The multiplicative inverse, using Fermat's little theorem `a^(P-2) = a^-1`.
+    ///
+    /// # Panics:
+    /// Panics if the element is zero.
+    pub fn inv(self) -> Gf<P> {
+        if self.0 == 0 {
+            panic!("attempt to divide by zero");
+        }
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64> Add for Gf<P> {
+    type Output = Gf<P>;
+    fn add(self, rhs: Gf<P>) -> Self::Output {
+        Gf(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Gf<P> {
+    type Output = Gf<P>;
+    fn sub(self, rhs: Gf<P>) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> Mul for Gf<P> {
+    type Output = Gf<P>;
+    fn mul(self, rhs: Gf<P>) -> Self::Output {
+        Gf(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Gf<P> {
+    type Output = Gf<P>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Gf<P>) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+impl<const P: u64> Neg for Gf<P> {
+    type Output = Gf<P>;
+    fn neg(self) -> Self::Output {
+        if self.0 == 0 {
+            self
+        } else {
+            Gf(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> ExactScalar for Gf<P> {}
+
+impl<const P: u64> Scalar for Gf<P> {
+    fn zero() -> Self {
+        Gf(0)
+    }
+
+    fn one() -> Self {
+        Gf(1 % P)
+    }
+
+    fn from_f64(v: f64) -> Self {
+        Gf::new(v.round() as i64)
+    }
+
+    /// The trivial absolute value: 0 for zero and 1 for every other element.
+    fn abs(&self) -> f64 {
+        if self.0 == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    fn epsilon() -> f64 {
+        0.0
+    }
+}
+
+impl<const P: u64> FromStr for Gf<P> {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Gf::new(s.trim().parse::<i64>()?))
+    }
+}
+
+impl<const P: u64> fmt::Display for Gf<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> fmt::Debug for Gf<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
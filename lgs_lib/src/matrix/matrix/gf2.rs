@@ -0,0 +1,240 @@
+/// # Gf2Matrix
+/// A bit-packed matrix over GF(2), the field with the elements 0 and 1.
+///
+/// Every row is stored as a slice of `u64` words, so adding one row to another
+/// is a word-wise XOR.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gf2Matrix {
+    pub height: usize,
+    pub width: usize,
+    words: usize,
+    data: Vec<u64>,
+}
+
+impl Gf2Matrix {
+    ///# Description:
+    /// Create a new matrix of the given height and width with all bits cleared.
+    pub fn new(height: usize, width: usize) -> Gf2Matrix {
+        let words = width.div_ceil(64);
+        Gf2Matrix {
+            height,
+            width,
+            words,
+            data: vec![0; height * words],
+        }
+    }
+
+    ///# Description:
+    /// Create the n×n identity matrix.
+    pub fn identity(n: usize) -> Gf2Matrix {
+        let mut m = Gf2Matrix::new(n, n);
+        for i in 0..n {
+            m.set(i, i, true);
+        }
+        m
+    }
+
+    ///# Description:
+    /// Create a new matrix from rows of bits.
+    ///
+    /// # Panics:
+    /// Panics if the rows are not all of the same length.
+    pub fn from_data(data: Vec<Vec<bool>>) -> Gf2Matrix {
+        let width = data.first().map_or(0, |r| r.len());
+        let mut m = Gf2Matrix::new(data.len(), width);
+        for (i, row) in data.iter().enumerate() {
+            if row.len() != width {
+                panic!("Row length does not match matrix width");
+            }
+            for (j, &bit) in row.iter().enumerate() {
+                m.set(i, j, bit);
+            }
+        }
+        m
+    }
+
+    pub fn get_data(&self) -> Vec<Vec<bool>> {
+        (0..self.height)
+            .map(|i| (0..self.width).map(|j| self.get(i, j)).collect())
+            .collect()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.row(row)[col / 64] >> (col % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, bit: bool) {
+        let word = &mut self.row_mut(row)[col / 64];
+        if bit {
+            *word |= 1 << (col % 64);
+        } else {
+            *word &= !(1 << (col % 64));
+        }
+    }
+
+    fn row(&self, i: usize) -> &[u64] {
+        &self.data[i * self.words..(i + 1) * self.words]
+    }
+
+    fn row_mut(&mut self, i: usize) -> &mut [u64] {
+        &mut self.data[i * self.words..(i + 1) * self.words]
+    }
+
+    ///# Description:
+    /// Add the row `src` to the row `dst`, which over GF(2) is an XOR.
+    pub fn xor_rows(&mut self, src: usize, dst: usize) {
+        if src == dst {
+            self.row_mut(dst).fill(0);
+            return;
+        }
+        let w = self.words;
+        let (lo, hi) = self.data.split_at_mut(src.max(dst) * w);
+        let (s, d) = if src < dst {
+            (&lo[src * w..(src + 1) * w], &mut hi[..w])
+        } else {
+            (&hi[..w] as &[u64], &mut lo[dst * w..(dst + 1) * w])
+        };
+        for (d, s) in d.iter_mut().zip(s) {
+            *d ^= s;
+        }
+    }
+
+    ///# Description:
+    /// Swap two rows in the matrix.
+    pub fn swap_rows(&mut self, row: usize, other: usize) {
+        if row >= self.height || other >= self.height {
+            panic!("Row or column index out of bounds");
+        }
+        for k in 0..self.words {
+            self.data.swap(row * self.words + k, other * self.words + k);
+        }
+    }
+
+    /// # Description:
+    /// Create the reduced row echelon form of the matrix.
+    /// Returns the reduced matrix together with the pivot column of every non-zero row.
+    pub fn rref(&self) -> (Gf2Matrix, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = vec![];
+        let mut r = 0;
+
+        for c in 0..m.width {
+            if r == m.height {
+                break;
+            }
+            let Some(p) = (r..m.height).find(|&i| m.get(i, c)) else {
+                continue;
+            };
+            m.swap_rows(r, p);
+            for i in 0..m.height {
+                if i != r && m.get(i, c) {
+                    m.xor_rows(r, i);
+                }
+            }
+            pivots.push(c);
+            r += 1;
+        }
+
+        (m, pivots)
+    }
+
+    /// # Description:
+    /// The rank of the matrix over GF(2).
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// # Description:
+    /// Solve `A·x = b` over GF(2).
+    /// If the system has several solutions, the one with all free variables set to 0 is returned.
+    ///
+    /// Returns `None` if the system has no solution.
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match the height of the matrix.
+    pub fn solve(&self, b: &[bool]) -> Option<Vec<bool>> {
+        if b.len() != self.height {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut aug = self.hstack_columns(1);
+        for (i, &bit) in b.iter().enumerate() {
+            aug.set(i, self.width, bit);
+        }
+
+        let (m, pivots) = aug.rref();
+        if pivots.last() == Some(&self.width) {
+            return None;
+        }
+        let mut x = vec![false; self.width];
+        for (row, &p) in pivots.iter().enumerate() {
+            x[p] = m.get(row, self.width);
+        }
+        Some(x)
+    }
+
+    /// # Description:
+    /// Calculate the inverse of the matrix over GF(2).
+    /// Returns `None` if the matrix is singular.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn inverse(&self) -> Option<Gf2Matrix> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let n = self.width;
+        if n == 0 {
+            return Some(Gf2Matrix::new(0, 0));
+        }
+        let mut aug = self.hstack_columns(n);
+        for i in 0..n {
+            aug.set(i, n + i, true);
+        }
+
+        let (m, pivots) = aug.rref();
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return None;
+        }
+        let mut inv = Gf2Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                inv.set(i, j, m.get(i, n + j));
+            }
+        }
+        Some(inv)
+    }
+
+    /// # Description:
+    /// A basis of the null space, all vectors `x` with `A·x = 0` over GF(2).
+    pub fn null_space(&self) -> Vec<Vec<bool>> {
+        let (m, pivots) = self.rref();
+        let mut basis = vec![];
+
+        for free in (0..self.width).filter(|c| !pivots.contains(c)) {
+            let mut x = vec![false; self.width];
+            x[free] = true;
+            for (row, &p) in pivots.iter().enumerate() {
+                x[p] = m.get(row, free);
+            }
+            basis.push(x);
+        }
+        basis
+    }
+
+    /// # Description:
+    /// Multiply the matrix with a vector of bits.
+    pub fn mul_vec(&self, x: &[bool]) -> Vec<bool> {
+        (0..self.height)
+            .map(|i| (0..self.width).filter(|&j| self.get(i, j) && x[j]).count() % 2 == 1)
+            .collect()
+    }
+
+    /// Copy of the matrix with `extra` cleared columns appended on the right.
+    fn hstack_columns(&self, extra: usize) -> Gf2Matrix {
+        let mut m = Gf2Matrix::new(self.height, self.width + extra);
+        for i in 0..self.height {
+            m.row_mut(i)[..self.words].copy_from_slice(self.row(i));
+        }
+        m
+    }
+}
@@ -0,0 +1,140 @@
+use super::BigInt;
+use std::fmt::Debug;
+
+/// # Integer
+/// The element type of integer matrices, used by the fraction-free algorithms.
+///
+/// Integers are not a field, so unlike [`Scalar`](super::Scalar) there is no general division.
+/// All operations are checked and return `None` if the result does not fit into the type.
+pub trait Integer: Clone + Debug + Ord {
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    fn is_negative(&self) -> bool;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_neg(&self) -> Option<Self>;
+
+    /// Divide by a divisor that is known to divide `self` without remainder.
+    fn checked_exact_div(&self, rhs: &Self) -> Option<Self>;
+
+    /// Euclidean division, the remainder `r` always satisfies `0 <= r < |rhs|`.
+    fn checked_div_rem_euclid(&self, rhs: &Self) -> Option<(Self, Self)>;
+
+    fn checked_abs(&self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self.clone())
+        }
+    }
+}
+
+impl Integer for i64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < 0
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_add(*self, *rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_sub(*self, *rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        i64::checked_mul(*self, *rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        i64::checked_neg(*self)
+    }
+
+    fn checked_exact_div(&self, rhs: &Self) -> Option<Self> {
+        debug_assert!(*rhs == 0 || self % rhs == 0, "{} is not divisible by {}", self, rhs);
+        i64::checked_div(*self, *rhs)
+    }
+
+    fn checked_div_rem_euclid(&self, rhs: &Self) -> Option<(Self, Self)> {
+        Some((self.checked_div_euclid(*rhs)?, self.checked_rem_euclid(*rhs)?))
+    }
+}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+
+    fn one() -> Self {
+        BigInt::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        BigInt::is_negative(self)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+
+    fn checked_exact_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self / rhs)
+    }
+
+    fn checked_div_rem_euclid(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (q, r) = self.div_rem(rhs);
+        if !r.is_negative() {
+            return Some((q, r));
+        }
+        if rhs.is_negative() {
+            Some((q + BigInt::one(), r - rhs.clone()))
+        } else {
+            Some((q - BigInt::one(), r + rhs.clone()))
+        }
+    }
+}
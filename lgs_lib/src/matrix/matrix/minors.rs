@@ -0,0 +1,174 @@
+use super::echelon::is_negligible;
+use super::{Matrix, Scalar};
+use crate::lgs::lgs::lu_decompose;
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The trace, the sum of the diagonal entries.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn trace(&self) -> T {
+        check_square(self);
+        (0..self.height).fold(T::zero(), |acc, i| acc + self[i][i].clone())
+    }
+
+    ///# Description:
+    /// The minor `M_ij`, the determinant of the matrix without row `i` and column `j`,
+    /// computed with [`Matrix::det`] in O(n³).
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square or the indices are out of bounds.
+    pub fn minor(&self, i: usize, j: usize) -> T {
+        check_square(self);
+        if i >= self.height || j >= self.width {
+            panic!("Row or column index out of bounds");
+        }
+        let rows: Vec<usize> = (0..self.height).filter(|&r| r != i).collect();
+        let cols: Vec<usize> = (0..self.width).filter(|&c| c != j).collect();
+        self.select(&rows, &cols).det()
+    }
+
+    ///# Description:
+    /// The cofactor `C_ij = (-1)^(i+j)·M_ij`.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square or the indices are out of bounds.
+    pub fn cofactor(&self, i: usize, j: usize) -> T {
+        let m = self.minor(i, j);
+        if (i + j) % 2 == 1 {
+            -m
+        } else {
+            m
+        }
+    }
+
+    ///# Description:
+    /// The matrix of all cofactors `C_ij`, the transposed [`Matrix::adjugate`].
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn cofactor_matrix(&self) -> Matrix<T> {
+        self.adjugate().transpose()
+    }
+
+    ///# Description:
+    /// The adjugate, the transposed cofactor matrix, with `A·adj(A) = det(A)·I`.
+    /// It exists for singular matrices as well, for invertible ones `A⁻¹ = adj(A) / det(A)`.
+    ///
+    /// For invertible matrices it is computed as `det(A)·A⁻¹` from a single LU decomposition
+    /// in O(n³), singular ones fall back to the n² minors.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn adjugate(&self) -> Matrix<T> {
+        check_square(self);
+        let n = self.height;
+        let lu = lu_decompose(self.clone());
+        let mut adj = Matrix::new(n, n);
+        if lu.is_singular() {
+            for i in 0..n {
+                for j in 0..n {
+                    adj[j][i] = self.cofactor(i, j);
+                }
+            }
+            return adj;
+        }
+
+        let det = lu.det();
+        for j in 0..n {
+            // column j of A⁻¹ solves A·x = e_j
+            let mut e = vec![T::zero(); n];
+            e[j] = T::one();
+            let x = lu.solve(&e);
+            for i in 0..n {
+                adj[i][j] = det.clone() * x[i].clone();
+            }
+        }
+        adj
+    }
+
+    ///# Description:
+    /// All principal minors of order `k`, the determinants of the submatrices that keep the same
+    /// `k` rows and columns, in lexicographic order of the kept indices.
+    /// There are `n choose k` of them, their sum is `(-1)^k` times the coefficient of `x^(n-k)`
+    /// in the characteristic polynomial.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square or `k` is larger than its size.
+    pub fn principal_minors(&self, k: usize) -> Vec<T> {
+        check_square(self);
+        if k > self.height {
+            panic!("Row or column index out of bounds");
+        }
+        let mut minors = vec![];
+        let mut indices: Vec<usize> = (0..k).collect();
+        loop {
+            minors.push(self.select(&indices, &indices).det());
+
+            // advance to the next k-subset in lexicographic order
+            let n = self.height;
+            let Some(p) = (0..k).rev().find(|&p| indices[p] < n - k + p) else {
+                return minors;
+            };
+            indices[p] += 1;
+            for q in p + 1..k {
+                indices[q] = indices[q - 1] + 1;
+            }
+        }
+    }
+
+    ///# Description:
+    /// The leading principal minors, the determinants of the upper left `1 × 1`, …, `n × n` blocks.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn leading_principal_minors(&self) -> Vec<T> {
+        check_square(self);
+        (1..=self.height)
+            .map(|k| {
+                let indices: Vec<usize> = (0..k).collect();
+                self.select(&indices, &indices).det()
+            })
+            .collect()
+    }
+
+    /// The submatrix of the given rows and columns.
+    fn select(&self, rows: &[usize], cols: &[usize]) -> Matrix<T> {
+        let mut m = Matrix::new(rows.len(), cols.len());
+        for (a, &i) in rows.iter().enumerate() {
+            for (b, &j) in cols.iter().enumerate() {
+                m[a][b] = self[i][j].clone();
+            }
+        }
+        m
+    }
+}
+
+impl<T: Scalar + PartialOrd> Matrix<T> {
+    ///# Description:
+    /// Check if the matrix is positive definite with Sylvester's criterion:
+    /// a symmetric matrix is positive definite if and only if all leading principal minors are positive.
+    ///
+    /// Non symmetric matrices are never positive definite.
+    /// For floating point types the `k`-th minor has to exceed `n·ε·‖A‖∞^k`,
+    /// so the result does not depend on the scale of the matrix.
+    pub fn is_positive_definite(&self) -> bool {
+        if !self.is_hermitian() {
+            return false;
+        }
+        let tol = self.zero_tolerance();
+        let norm = self.norm_inf();
+        self.leading_principal_minors()
+            .iter()
+            .enumerate()
+            .all(|(k, m)| !is_negligible(m, tol * norm.powi(k as i32)) && *m > T::zero())
+    }
+}
+
+fn check_square<T>(m: &Matrix<T>) {
+    if m.width != m.height {
+        panic!("Matrix must be square");
+    }
+}
+
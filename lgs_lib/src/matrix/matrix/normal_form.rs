@@ -0,0 +1,202 @@
+use super::{Integer, Matrix};
+
+impl<T: Integer> Matrix<T> {
+    /// # Description:
+    /// Calculate the (row-style) Hermite normal form `H = U·A` of an integer matrix.
+    ///
+    /// `H` is in row echelon form, every pivot is positive and the entries above a pivot
+    /// are reduced to `0 <= h < pivot`. `U` is unimodular, a product of integer row operations
+    /// with determinant ±1.
+    ///
+    /// Returns `(H, U)`, or `None` if an intermediate value overflows `T`.
+    pub fn hermite_normal_form(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let mut h = self.clone();
+        let mut u = identity(self.height);
+        let mut r = 0;
+
+        for c in 0..h.width {
+            if r == h.height {
+                break;
+            }
+            // Euclid on the column: move the smallest entry up and reduce all others below it.
+            while let Some((p, _)) = min_abs_entry(&h, r..h.height, c..c + 1) {
+                h.swap_rows(r, p);
+                u.swap_rows(r, p);
+                let mut reduced = true;
+                for i in (r + 1)..h.height {
+                    if h[i][c].is_zero() {
+                        continue;
+                    }
+                    let (q, _) = h[i][c].checked_div_rem_euclid(&h[r][c])?;
+                    sub_row_multiple(&mut h, r, i, &q)?;
+                    sub_row_multiple(&mut u, r, i, &q)?;
+                    reduced &= h[i][c].is_zero();
+                }
+                if reduced {
+                    break;
+                }
+            }
+            if h[r][c].is_zero() {
+                continue;
+            }
+
+            if h[r][c].is_negative() {
+                negate_row(&mut h, r)?;
+                negate_row(&mut u, r)?;
+            }
+            for i in 0..r {
+                let (q, _) = h[i][c].checked_div_rem_euclid(&h[r][c])?;
+                sub_row_multiple(&mut h, r, i, &q)?;
+                sub_row_multiple(&mut u, r, i, &q)?;
+            }
+            r += 1;
+        }
+
+        Some((h, u))
+    }
+
+    /// # Description:
+    /// Calculate the Smith normal form `S = U·A·V` of an integer matrix.
+    ///
+    /// `S` is diagonal with non-negative invariant factors `d1 | d2 | ... | dr`
+    /// followed by zeros. `U` and `V` are unimodular.
+    /// The invariant factors describe the abelian group `Z^m / A·Z^n`.
+    ///
+    /// Returns `(S, U, V)`, or `None` if an intermediate value overflows `T`.
+    pub fn smith_normal_form(&self) -> Option<(Matrix<T>, Matrix<T>, Matrix<T>)> {
+        let mut s = self.clone();
+        let mut u = identity(self.height);
+        let mut v = identity(self.width);
+
+        for t in 0..s.height.min(s.width) {
+            loop {
+                let Some((p, q)) = min_abs_entry(&s, t..s.height, t..s.width) else {
+                    return Some((s, u, v));
+                };
+                s.swap_rows(t, p);
+                u.swap_rows(t, p);
+                swap_cols(&mut s, t, q);
+                swap_cols(&mut v, t, q);
+
+                let mut reduced = true;
+                for i in (t + 1)..s.height {
+                    if s[i][t].is_zero() {
+                        continue;
+                    }
+                    let (k, _) = s[i][t].checked_div_rem_euclid(&s[t][t])?;
+                    sub_row_multiple(&mut s, t, i, &k)?;
+                    sub_row_multiple(&mut u, t, i, &k)?;
+                    reduced &= s[i][t].is_zero();
+                }
+                for j in (t + 1)..s.width {
+                    if s[t][j].is_zero() {
+                        continue;
+                    }
+                    let (k, _) = s[t][j].checked_div_rem_euclid(&s[t][t])?;
+                    sub_col_multiple(&mut s, t, j, &k)?;
+                    sub_col_multiple(&mut v, t, j, &k)?;
+                    reduced &= s[t][j].is_zero();
+                }
+                if !reduced {
+                    continue;
+                }
+
+                // The pivot has to divide every remaining entry, otherwise pull a
+                // non-divisible row into the pivot row and reduce again.
+                let mut indivisible = None;
+                'rows: for i in (t + 1)..s.height {
+                    for j in (t + 1)..s.width {
+                        if !s[i][j].checked_div_rem_euclid(&s[t][t])?.1.is_zero() {
+                            indivisible = Some(i);
+                            break 'rows;
+                        }
+                    }
+                }
+                match indivisible {
+                    Some(i) => {
+                        let minus_one = T::one().checked_neg()?;
+                        sub_row_multiple(&mut s, i, t, &minus_one)?;
+                        sub_row_multiple(&mut u, i, t, &minus_one)?;
+                    }
+                    None => break,
+                }
+            }
+
+            if s[t][t].is_negative() {
+                negate_row(&mut s, t)?;
+                negate_row(&mut u, t)?;
+            }
+        }
+
+        Some((s, u, v))
+    }
+}
+
+fn identity<T: Integer>(n: usize) -> Matrix<T> {
+    Matrix {
+        height: n,
+        width: n,
+        data: (0..n)
+            .map(|i| (0..n).map(|j| if i == j { T::one() } else { T::zero() }).collect())
+            .collect(),
+    }
+}
+
+/// The position of the non-zero entry with the smallest absolute value in the given block.
+fn min_abs_entry<T: Integer>(
+    m: &Matrix<T>,
+    rows: std::ops::Range<usize>,
+    cols: std::ops::Range<usize>,
+) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    for i in rows {
+        for j in cols.clone() {
+            if m[i][j].is_zero() {
+                continue;
+            }
+            if best.is_none_or(|(bi, bj)| abs_lt(&m[i][j], &m[bi][bj])) {
+                best = Some((i, j));
+            }
+        }
+    }
+    best
+}
+
+/// `|a| < |b|`, compared without negating, so it cannot overflow.
+fn abs_lt<T: Integer>(a: &T, b: &T) -> bool {
+    match (a.is_negative(), b.is_negative()) {
+        (false, false) => a < b,
+        (true, true) => a > b,
+        (true, false) => b.checked_neg().is_some_and(|nb| *a > nb),
+        (false, true) => a.checked_neg().is_some_and(|na| na > *b),
+    }
+}
+
+/// Row `dst` ← row `dst` − q · row `src`.
+fn sub_row_multiple<T: Integer>(m: &mut Matrix<T>, src: usize, dst: usize, q: &T) -> Option<()> {
+    for j in 0..m.width {
+        m[dst][j] = m[dst][j].checked_sub(&m[src][j].checked_mul(q)?)?;
+    }
+    Some(())
+}
+
+/// Column `dst` ← column `dst` − q · column `src`.
+fn sub_col_multiple<T: Integer>(m: &mut Matrix<T>, src: usize, dst: usize, q: &T) -> Option<()> {
+    for i in 0..m.height {
+        m[i][dst] = m[i][dst].checked_sub(&m[i][src].checked_mul(q)?)?;
+    }
+    Some(())
+}
+
+fn negate_row<T: Integer>(m: &mut Matrix<T>, i: usize) -> Option<()> {
+    for j in 0..m.width {
+        m[i][j] = m[i][j].checked_neg()?;
+    }
+    Some(())
+}
+
+fn swap_cols<T>(m: &mut Matrix<T>, a: usize, b: usize) {
+    for row in m.data.iter_mut() {
+        row.swap(a, b);
+    }
+}
@@ -1,75 +1,233 @@
-use super::Matrix;
+use super::parallel::for_each_row;
+use super::{Complex, Gf, Matrix, Rational, Scalar};
     use std::ops::{self, Index, IndexMut};
-    impl ops::Add<Matrix> for Matrix {
-        type Output = Matrix;
-        fn add(mut self, m: Matrix) -> Self::Output {
-            for i in 0..self.height {
-                for j in 0..self.width {
-                    self[i][j] += m[i][j];
+
+    fn check_same_size<T>(a: &Matrix<T>, b: &Matrix<T>) {
+        if a.height != b.height || a.width != b.width {
+            panic!("Matrix dimensions do not match");
+        }
+    }
+
+    /// The number of entries, the amount of work for element-wise operations.
+    fn entries<T>(m: &Matrix<T>) -> usize {
+        m.height * m.width
+    }
+
+    impl<T: Scalar> ops::AddAssign<&Matrix<T>> for Matrix<T> {
+        fn add_assign(&mut self, m: &Matrix<T>) {
+            check_same_size(self, m);
+            let work = entries(self);
+            for_each_row(&mut self.data, work, |i, row| {
+                for (a, b) in row.iter_mut().zip(&m[i]) {
+                    *a = a.clone() + b.clone();
                 }
-            }
-            self
+            });
         }
     }
 
-    impl ops::Sub<Matrix> for Matrix {
-        type Output = Matrix;
-        fn sub(mut self, m: Matrix) -> Self::Output {
-            for i in 0..self.height {
-                for j in 0..self.width {
-                    self[i][j] -= m[i][j];
+    impl<T: Scalar> ops::SubAssign<&Matrix<T>> for Matrix<T> {
+        fn sub_assign(&mut self, m: &Matrix<T>) {
+            check_same_size(self, m);
+            let work = entries(self);
+            for_each_row(&mut self.data, work, |i, row| {
+                for (a, b) in row.iter_mut().zip(&m[i]) {
+                    *a = a.clone() - b.clone();
                 }
-            }
-            self
+            });
         }
     }
 
-    impl ops::Mul<f64> for Matrix {
-        // todo: run multithreaded
-        type Output = Matrix;
-        fn mul(mut self, v: f64) -> Self::Output {
-            for i in 0..self.height {
-                for j in 0..self.width {
-                    self[i][j] *= v;
+    impl<T: Scalar> ops::MulAssign<T> for Matrix<T> {
+        fn mul_assign(&mut self, v: T) {
+            let work = entries(self);
+            for_each_row(&mut self.data, work, |_, row| {
+                for a in row.iter_mut() {
+                    *a = a.clone() * v.clone();
                 }
-            }
+            });
+        }
+    }
+
+    impl<T: Scalar> ops::DivAssign<T> for Matrix<T> {
+        fn div_assign(&mut self, rhs: T) {
+            let work = entries(self);
+            for_each_row(&mut self.data, work, |_, row| {
+                for a in row.iter_mut() {
+                    *a = a.clone() / rhs.clone();
+                }
+            });
+        }
+    }
+
+    impl<T: Scalar> ops::Mul<&Matrix<T>> for &Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, m: &Matrix<T>) -> Self::Output {
+            self.mul_blocked(m)
+        }
+    }
+
+    impl<T: Scalar> ops::Neg for Matrix<T> {
+        type Output = Matrix<T>;
+        fn neg(mut self) -> Self::Output {
+            let work = entries(&self);
+            for_each_row(&mut self.data, work, |_, row| {
+                for a in row.iter_mut() {
+                    *a = -a.clone();
+                }
+            });
             self
         }
     }
 
-    impl ops::Mul<Matrix> for Matrix {
-        type Output = Matrix;
-        fn mul(self, m: Matrix) -> Self::Output {
-            if self.width != m.height {
-                panic!("Matrix dimensions do not match");
+    impl<T: Scalar> ops::Neg for &Matrix<T> {
+        type Output = Matrix<T>;
+        fn neg(self) -> Self::Output {
+            -self.clone()
+        }
+    }
+
+    // The remaining combinations of owned values and references forward to the impls above.
+    // Owned left hand sides are reused as the result instead of allocating a new matrix.
+
+    macro_rules! forward_elementwise_op {
+        ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+            impl<T: Scalar> ops::$assign_imp<Matrix<T>> for Matrix<T> {
+                fn $assign_method(&mut self, m: Matrix<T>) {
+                    ops::$assign_imp::$assign_method(self, &m);
+                }
             }
 
-            let mut tmp = Matrix::new(self.height, m.width);
+            impl<T: Scalar> ops::$imp<Matrix<T>> for Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(mut self, m: Matrix<T>) -> Self::Output {
+                    ops::$assign_imp::$assign_method(&mut self, &m);
+                    self
+                }
+            }
 
-            for i in 0..self.height {
-                for j in 0..m.width {
-                    for k in 0..self.width {
-                        tmp[i][j] += self[i][k] * m[k][j];
-                    }
+            impl<T: Scalar> ops::$imp<&Matrix<T>> for Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(mut self, m: &Matrix<T>) -> Self::Output {
+                    ops::$assign_imp::$assign_method(&mut self, m);
+                    self
                 }
             }
-            tmp
-        }
+
+            impl<T: Scalar> ops::$imp<Matrix<T>> for &Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(self, m: Matrix<T>) -> Self::Output {
+                    ops::$imp::$method(self, &m)
+                }
+            }
+
+            impl<T: Scalar> ops::$imp<&Matrix<T>> for &Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(self, m: &Matrix<T>) -> Self::Output {
+                    let mut tmp = self.clone();
+                    ops::$assign_imp::$assign_method(&mut tmp, m);
+                    tmp
+                }
+            }
+        };
     }
 
-    impl ops::Div<f64> for Matrix {
-        type Output = Matrix;
-        fn div(mut self, rhs: f64) -> Self::Output {
-            for i in 0..self.height {
-                for j in 0..self.width {
-                    self[i][j] /= rhs;
+    forward_elementwise_op!(Add, add, AddAssign, add_assign);
+    forward_elementwise_op!(Sub, sub, SubAssign, sub_assign);
+
+    macro_rules! forward_scalar_op {
+        ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+            impl<T: Scalar> ops::$imp<T> for Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(mut self, v: T) -> Self::Output {
+                    ops::$assign_imp::$assign_method(&mut self, v);
+                    self
                 }
             }
-            self
+
+            impl<T: Scalar> ops::$imp<T> for &Matrix<T> {
+                type Output = Matrix<T>;
+                fn $method(self, v: T) -> Self::Output {
+                    ops::$imp::$method(self.clone(), v)
+                }
+            }
+        };
+    }
+
+    forward_scalar_op!(Mul, mul, MulAssign, mul_assign);
+    forward_scalar_op!(Div, div, DivAssign, div_assign);
+
+    impl<T: Scalar> ops::Mul<Matrix<T>> for Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, m: Matrix<T>) -> Self::Output {
+            &self * &m
+        }
+    }
+
+    impl<T: Scalar> ops::Mul<&Matrix<T>> for Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, m: &Matrix<T>) -> Self::Output {
+            &self * m
         }
     }
 
-    impl PartialEq for Matrix {
+    impl<T: Scalar> ops::Mul<Matrix<T>> for &Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, m: Matrix<T>) -> Self::Output {
+            self * &m
+        }
+    }
+
+    impl<T: Scalar> ops::MulAssign<Matrix<T>> for Matrix<T> {
+        fn mul_assign(&mut self, m: Matrix<T>) {
+            *self = &*self * &m;
+        }
+    }
+
+    impl<T: Scalar> ops::MulAssign<&Matrix<T>> for Matrix<T> {
+        fn mul_assign(&mut self, m: &Matrix<T>) {
+            *self = &*self * m;
+        }
+    }
+
+    // Scalars on the left hand side, e.g. `2.0 * m`.
+    // The orphan rules do not allow a blanket impl over `T`, so every scalar type gets its own.
+    macro_rules! impl_scalar_mul_matrix {
+        ($($t:ty),*) => {
+            $(
+                impl ops::Mul<Matrix<$t>> for $t {
+                    type Output = Matrix<$t>;
+                    fn mul(self, m: Matrix<$t>) -> Self::Output {
+                        m * self
+                    }
+                }
+
+                impl ops::Mul<&Matrix<$t>> for $t {
+                    type Output = Matrix<$t>;
+                    fn mul(self, m: &Matrix<$t>) -> Self::Output {
+                        m * self
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_scalar_mul_matrix!(f32, f64, Complex, Rational);
+
+    impl<const P: u64> ops::Mul<Matrix<Gf<P>>> for Gf<P> {
+        type Output = Matrix<Gf<P>>;
+        fn mul(self, m: Matrix<Gf<P>>) -> Self::Output {
+            m * self
+        }
+    }
+
+    impl<const P: u64> ops::Mul<&Matrix<Gf<P>>> for Gf<P> {
+        type Output = Matrix<Gf<P>>;
+        fn mul(self, m: &Matrix<Gf<P>>) -> Self::Output {
+            m * self
+        }
+    }
+
+    impl<T: PartialEq> PartialEq for Matrix<T> {
         fn eq(&self, other: &Self) -> bool {
             if self.height != other.height || self.width != other.width {
                 return false;
@@ -84,21 +242,17 @@ use super::Matrix;
             }
             true
         }
-
-        fn ne(&self, other: &Self) -> bool {
-            !self.eq(other)
-        }
     }
 
-    impl Index<usize> for Matrix {
-        type Output = Vec<f64>;
-        fn index(&self, index: usize) -> &Vec<f64> {
+    impl<T> Index<usize> for Matrix<T> {
+        type Output = Vec<T>;
+        fn index(&self, index: usize) -> &Vec<T> {
             &self.data[index]
         }
     }
 
-    impl IndexMut<usize> for Matrix {
-        fn index_mut(&mut self, i: usize) -> &mut Vec<f64> {
+    impl<T> IndexMut<usize> for Matrix<T> {
+        fn index_mut(&mut self, i: usize) -> &mut Vec<T> {
             &mut self.data[i]
         }
     }
@@ -0,0 +1,112 @@
+use super::{Matrix, Scalar, Vector};
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// Orthonormalize the columns with modified Gram-Schmidt.
+    /// Every column is orthogonalized twice against the previous ones,
+    /// which keeps the result orthogonal to working precision even for ill conditioned columns.
+    ///
+    /// Columns that are linear dependent on the previous ones are dropped,
+    /// so the result has orthonormal columns spanning the column space and its width is the rank.
+    /// Only meaningful for real and complex matrices, the columns are scaled with [`Vector::normalize`].
+    ///
+    /// # Arguments:
+    /// - tol: f64, a column is dependent if what remains after orthogonalization is at most
+    ///   `tol` times the largest column norm, e.g. `1e-10`.
+    pub fn gram_schmidt(&self, tol: f64) -> Matrix<T> {
+        let basis = Matrix::orthonormal_basis(&self.columns(), tol);
+        let mut q = Matrix::new(self.height, basis.len());
+        for (j, v) in basis.iter().enumerate() {
+            for i in 0..self.height {
+                q[i][j] = v[i].clone();
+            }
+        }
+        q
+    }
+
+    ///# Description:
+    /// An orthonormal basis of the span of the vectors, see [`Matrix::gram_schmidt`].
+    /// Like it, this needs real or complex scalars, a finite field has no notion of length.
+    ///
+    /// # Panics:
+    /// Panics if the vectors differ in length.
+    pub fn orthonormal_basis(vectors: &[Vector<T>], tol: f64) -> Vec<Vector<T>> {
+        let scale = vectors.iter().map(|v| v.norm()).fold(0.0, f64::max);
+        let mut basis = vec![];
+        for v in vectors {
+            let v = orthogonalize(v.clone(), &basis);
+            if v.norm() > tol * scale {
+                basis.extend(v.normalize());
+            }
+        }
+        basis
+    }
+
+    ///# Description:
+    /// An orthonormal basis of the column space (range), all vectors `A·x`.
+    pub fn column_space(&self, tol: f64) -> Vec<Vector<T>> {
+        Matrix::orthonormal_basis(&self.columns(), tol)
+    }
+
+    ///# Description:
+    /// An orthonormal basis of the row space, the span of the rows.
+    pub fn row_space(&self, tol: f64) -> Vec<Vector<T>> {
+        self.transpose().column_space(tol)
+    }
+
+    ///# Description:
+    /// An orthonormal basis of the null space, all vectors `x` with `A·x = 0`.
+    ///
+    /// Unlike [`Matrix::null_space`], which is exact for fields like rationals,
+    /// the rank is decided with the tolerance and the basis is orthonormal.
+    /// The basis completes the row space of `conj(A)` to the whole space.
+    pub fn orthonormal_null_space(&self, tol: f64) -> Vec<Vector<T>> {
+        let conj_rows: Vec<Vector<T>> = self.adjoint().columns();
+        complement(Matrix::orthonormal_basis(&conj_rows, tol), self.width)
+    }
+
+    ///# Description:
+    /// An orthonormal basis of the left null space, all vectors `y` with `yᴴ·A = 0`.
+    /// These are the vectors orthogonal to the column space.
+    pub fn left_null_space(&self, tol: f64) -> Vec<Vector<T>> {
+        complement(self.column_space(tol), self.height)
+    }
+
+    fn columns(&self) -> Vec<Vector<T>> {
+        (0..self.width)
+            .map(|j| (0..self.height).map(|i| self[i][j].clone()).collect())
+            .collect()
+    }
+}
+
+/// Subtract the projections onto the orthonormal `basis`, one after another, twice.
+fn orthogonalize<T: Scalar>(mut v: Vector<T>, basis: &[Vector<T>]) -> Vector<T> {
+    for _ in 0..2 {
+        for q in basis {
+            let c = q.dot(&v);
+            v.axpy(-c, q);
+        }
+    }
+    v
+}
+
+/// Extend an orthonormal basis to the whole space of dimension `n` and return only the new vectors.
+/// Each step adds the unit vector with the largest part orthogonal to the current basis,
+/// which is at least `1/√n`, so no tolerance is needed.
+fn complement<T: Scalar>(mut basis: Vec<Vector<T>>, n: usize) -> Vec<Vector<T>> {
+    let rank = basis.len();
+    while basis.len() < n {
+        let next = (0..n)
+            .map(|i| {
+                let mut e = Vector::new(n);
+                e[i] = T::one();
+                orthogonalize(e, &basis)
+            })
+            .max_by(|a, b| a.norm().total_cmp(&b.norm()));
+        match next.and_then(|v| v.normalize()) {
+            Some(v) => basis.push(v),
+            None => break,
+        }
+    }
+    basis.split_off(rank)
+}
@@ -0,0 +1,129 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use std::fmt;
+#[cfg(feature = "parallel")]
+use std::sync::OnceLock;
+
+/// Operations touching fewer entries than this run on the calling thread,
+/// for smaller matrices the overhead of the thread pool outweighs the gain.
+pub const PARALLEL_THRESHOLD: usize = 1 << 15;
+
+#[cfg(feature = "parallel")]
+static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+/// Error returned by [`configure_thread_pool`].
+#[cfg(feature = "parallel")]
+#[derive(Debug)]
+pub enum ThreadPoolError {
+    /// The pool was already configured, it can only be set up once per process.
+    AlreadyConfigured,
+    /// Rayon failed to spawn the threads.
+    Build(rayon::ThreadPoolBuildError),
+}
+
+#[cfg(feature = "parallel")]
+impl fmt::Display for ThreadPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThreadPoolError::AlreadyConfigured => write!(f, "thread pool is already configured"),
+            ThreadPoolError::Build(e) => write!(f, "failed to build thread pool: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl std::error::Error for ThreadPoolError {}
+
+///# Description:
+/// Use a dedicated pool of `num_threads` threads for all parallel matrix operations.
+/// Without a call to this function the global rayon pool is used,
+/// which can be sized with the `RAYON_NUM_THREADS` environment variable.
+///
+/// # Arguments:
+/// - num_threads: usize, the number of threads, 0 picks one per CPU.
+#[cfg(feature = "parallel")]
+pub fn configure_thread_pool(num_threads: usize) -> Result<(), ThreadPoolError> {
+    if POOL.get().is_some() {
+        return Err(ThreadPoolError::AlreadyConfigured);
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|i| format!("lgs-worker-{}", i))
+        .build()
+        .map_err(ThreadPoolError::Build)?;
+    POOL.set(pool).map_err(|_| ThreadPoolError::AlreadyConfigured)
+}
+
+///# Description:
+/// The number of threads parallel operations run on, 1 without the `parallel` feature.
+pub fn current_num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        match POOL.get() {
+            Some(pool) => pool.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Run `f` for every chunk of `chunk` rows, together with the index of its first row.
+/// The chunks are spread over the thread pool if `work` reaches `threshold`.
+///
+/// Every chunk is processed by exactly the same code in both cases,
+/// so results do not depend on whether the feature is enabled.
+pub(crate) fn for_each_chunk<T, F>(rows: &mut [Vec<T>], chunk: usize, work: usize, threshold: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [Vec<T>]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if work >= threshold && rows.len() > chunk {
+        install(|| {
+            rows.par_chunks_mut(chunk)
+                .enumerate()
+                .for_each(|(c, rows)| f(c * chunk, rows))
+        });
+        return;
+    }
+    let _ = (work, threshold);
+    for (c, rows) in rows.chunks_mut(chunk).enumerate() {
+        f(c * chunk, rows);
+    }
+}
+
+/// Run `f` for every row together with its index, in parallel above [`PARALLEL_THRESHOLD`].
+pub(crate) fn for_each_row<T, F>(rows: &mut [Vec<T>], work: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut Vec<T>) + Send + Sync,
+{
+    for_each_chunk(rows, 1, work, PARALLEL_THRESHOLD, |i, rows| f(i, &mut rows[0]));
+}
+
+/// Evaluate `f(0), …, f(n - 1)` in parallel above [`PARALLEL_THRESHOLD`].
+/// The results are returned in order, so reducing them afterwards gives the serial result.
+pub(crate) fn map_range<R, F>(n: usize, work: usize, f: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(usize) -> R + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if work >= PARALLEL_THRESHOLD {
+        return install(|| (0..n).into_par_iter().map(&f).collect());
+    }
+    let _ = work;
+    (0..n).map(f).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn install<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+    match POOL.get() {
+        Some(pool) => pool.install(f),
+        None => f(),
+    }
+}
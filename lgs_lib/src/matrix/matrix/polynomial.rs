@@ -0,0 +1,268 @@
+use super::{ExactScalar, Matrix, Scalar};
+use std::fmt::Display;
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The characteristic polynomial `det(x·I - A)`, as coefficients in ascending powers:
+    /// `[c_0, c_1, …, c_(n-1), 1]` stands for `c_0 + c_1·x + … + x^n`.
+    ///
+    /// The matrix is reduced to upper Hessenberg form with elementary similarity transforms,
+    /// then the polynomial follows from a recurrence over the leading blocks in O(n³).
+    /// Only field operations are used, so it works over every scalar type,
+    /// and exactly for rationals and finite fields.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn char_poly(&self) -> Vec<T> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let n = self.height;
+        let h = hessenberg(self.clone());
+
+        // p[k] is the characteristic polynomial of the leading k × k block
+        let mut p: Vec<Vec<T>> = vec![vec![T::one()]];
+        for k in 0..n {
+            // (x - h_kk)·p_k
+            let mut next = vec![T::zero(); k + 2];
+            for (i, c) in p[k].iter().enumerate() {
+                next[i + 1] = next[i + 1].clone() + c.clone();
+                next[i] = next[i].clone() - h[k][k].clone() * c.clone();
+            }
+            // - Σ h_(k-i),k · h_(k-i+1),(k-i) ⋯ h_k,(k-1) · p_(k-i)
+            let mut t = T::one();
+            for i in 1..=k {
+                t = t * h[k - i + 1][k - i].clone();
+                let f = t.clone() * h[k - i][k].clone();
+                for (j, c) in p[k - i].iter().enumerate() {
+                    next[j] = next[j].clone() - f.clone() * c.clone();
+                }
+            }
+            p.push(next);
+        }
+        p.pop().unwrap_or_default()
+    }
+
+    ///# Description:
+    /// Evaluate the polynomial with coefficients in ascending powers at the matrix,
+    /// `c_0·I + c_1·A + c_2·A² + …`, with Horner's scheme.
+    ///
+    /// By Cayley-Hamilton every matrix is a root of its characteristic polynomial,
+    /// so `a.eval_polynomial(&a.char_poly())` is the zero matrix.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn eval_polynomial(&self, coeffs: &[T]) -> Matrix<T> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let mut result = Matrix::new(self.height, self.width);
+        for c in coeffs.iter().rev() {
+            result = &result * self;
+            for i in 0..self.height {
+                result[i][i] = result[i][i].clone() + c.clone();
+            }
+        }
+        result
+    }
+}
+
+impl<T: ExactScalar> Matrix<T> {
+    ///# Description:
+    /// The minimal polynomial, the monic polynomial of least degree with `p(A) = 0`,
+    /// as coefficients in ascending powers. It divides the characteristic polynomial.
+    ///
+    /// Found as the first linear dependency between `I, A, A², …`,
+    /// which needs exact zero tests and is therefore only offered for exact scalars.
+    ///
+    /// # Panics:
+    /// Panics if the matrix is not square.
+    pub fn minimal_poly(&self) -> Vec<T> {
+        if self.width != self.height {
+            panic!("Matrix must be square");
+        }
+        let n = self.height;
+        if n == 0 {
+            return vec![T::one()];
+        }
+
+        // the columns of `krylov` are vec(A^0), …, vec(A^k)
+        let mut powers: Vec<Matrix<T>> = vec![Matrix::identity(n)];
+        loop {
+            let k = powers.len();
+            let mut krylov = Matrix::new(n * n, k);
+            for (j, p) in powers.iter().enumerate() {
+                for (i, x) in p.vec().into_iter().enumerate() {
+                    krylov[i][j] = x;
+                }
+            }
+            if let Some(c) = krylov.null_space().into_iter().next() {
+                // the earlier powers are independent, so the last coefficient is not zero
+                let lead = c[k - 1].clone();
+                return c.into_iter().map(|x| x / lead.clone()).collect();
+            }
+            let next = powers.last().map(|p| p * self).unwrap();
+            powers.push(next);
+        }
+    }
+}
+
+///# Description:
+/// Write a polynomial given by coefficients in ascending powers in the usual form,
+/// with the highest power first, e.g. `[-6, 11, -6, 1]` as `x^3 - 6x^2 + 11x - 6`.
+///
+/// Fractions and complex coefficients of a power are put in parentheses, e.g. `(5/6)x`.
+/// Floating point coefficients are rounded to [`POLYNOMIAL_DIGITS`] significant digits,
+/// and coefficients within rounding error of zero, relative to the largest one, are left out.
+///
+/// # Arguments:
+/// - coeffs: the coefficients, `coeffs[k]` belongs to `x^k`
+/// - var: the name of the variable
+pub fn format_polynomial<T: Scalar + Display>(coeffs: &[T], var: &str) -> String {
+    let exact = T::epsilon() == 0.0;
+    let largest = coeffs.iter().map(|c| c.abs()).fold(0.0, f64::max);
+    let noise = T::epsilon() * coeffs.len() as f64 * largest;
+
+    let mut out = String::new();
+    for (k, c) in coeffs.iter().enumerate().rev() {
+        if c.is_zero() || c.abs() <= noise {
+            continue;
+        }
+        let mut text = if exact { c.to_string() } else { round_numbers(&c.to_string(), POLYNOMIAL_DIGITS) };
+        let negative = text.starts_with('-') && !text[1..].contains(['+', '-']);
+        if negative {
+            text.remove(0);
+        }
+        let unit = *c == T::one() || *c == -T::one() || text == "1";
+        if text.contains(['+', '-']) || (k > 0 && text.contains('/')) {
+            // compound values like complex numbers, and fractions that would read as `5/(6x)`
+            text = format!("({})", text);
+        }
+
+        if out.is_empty() {
+            out.push_str(if negative { "-" } else { "" });
+        } else {
+            out.push_str(if negative { " - " } else { " + " });
+        }
+        if k == 0 || !unit {
+            out.push_str(&text);
+        }
+        match k {
+            0 => {}
+            1 => out.push_str(var),
+            _ => out.push_str(&format!("{}^{}", var, k)),
+        }
+    }
+    if out.is_empty() {
+        out.push('0');
+    }
+    out
+}
+
+/// The significant digits [`format_polynomial`] keeps of floating point coefficients.
+pub const POLYNOMIAL_DIGITS: usize = 10;
+
+/// Round every decimal number in the text to `digits` significant digits,
+/// e.g. `0.01999999999999999` to `0.02`, and `1-2.0000000000000004i` to `1-2i`.
+fn round_numbers(text: &str, digits: usize) -> String {
+    let bytes = text.as_bytes();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            out.push(text[i..].chars().next().unwrap_or_default());
+            i += text[i..].chars().next().map_or(1, char::len_utf8);
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+            i += 1;
+        }
+        if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+            let mut j = i + 1;
+            if j < bytes.len() && (bytes[j] == b'-' || bytes[j] == b'+') {
+                j += 1;
+            }
+            if j < bytes.len() && bytes[j].is_ascii_digit() {
+                i = j;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+        }
+        match text[start..i].parse::<f64>() {
+            Ok(v) => out.push_str(&significant(v, digits)),
+            Err(_) => out.push_str(&text[start..i]),
+        }
+    }
+    out
+}
+
+/// Write a non-negative number with `digits` significant digits and without trailing zeros,
+/// positional like the `Display` of floats, as exponents would read as compound values.
+fn significant(v: f64, digits: usize) -> String {
+    if v == 0.0 || !v.is_finite() {
+        return v.to_string();
+    }
+    let text = format!("{:.*e}", digits.max(1) - 1, v);
+    let (mantissa, exp) = text.split_once('e').unwrap_or((&text, "0"));
+    let exp: i32 = exp.parse().unwrap_or(0);
+    let digits: String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+    let point = exp + 1;
+    let text = if point <= 0 {
+        format!("0.{}{}", "0".repeat(point.unsigned_abs() as usize), digits)
+    } else if point as usize >= digits.len() {
+        format!("{}{}", digits, "0".repeat(point as usize - digits.len()))
+    } else {
+        format!("{}.{}", &digits[..point as usize], &digits[point as usize..])
+    };
+    trim_zeros(&text).to_string()
+}
+
+fn trim_zeros(text: &str) -> &str {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        text
+    }
+}
+
+/// Reduce to upper Hessenberg form with Gaussian similarity transforms `A ← S·A·S⁻¹`,
+/// pivoting on the largest entry below the subdiagonal.
+/// Only exact zeros are skipped, so the result does not depend on the scale of the matrix.
+fn hessenberg<T: Scalar>(mut h: Matrix<T>) -> Matrix<T> {
+    let n = h.height;
+    for m in 1..n.saturating_sub(1) {
+        let mut p = m;
+        for i in m + 1..n {
+            if h[i][m - 1].abs() > h[p][m - 1].abs() {
+                p = i;
+            }
+        }
+        if h[p][m - 1] == T::zero() {
+            continue;
+        }
+        if p != m {
+            h.swap_rows(p, m);
+            for i in 0..n {
+                let (a, b) = (h[i][p].clone(), h[i][m].clone());
+                h[i][p] = b;
+                h[i][m] = a;
+            }
+        }
+        for i in m + 1..n {
+            let u = h[i][m - 1].clone() / h[m][m - 1].clone();
+            if u == T::zero() {
+                continue;
+            }
+            // row_i -= u·row_m, then col_m += u·col_i
+            for j in 0..n {
+                h[i][j] = h[i][j].clone() - u.clone() * h[m][j].clone();
+            }
+            for j in 0..n {
+                h[j][m] = h[j][m].clone() + u.clone() * h[j][i].clone();
+            }
+        }
+    }
+    h
+}
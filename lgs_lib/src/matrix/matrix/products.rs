@@ -0,0 +1,116 @@
+use super::{Matrix, Scalar, Vector};
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// The Kronecker product `A ⊗ B`, the block matrix `[a_ij·B]`
+    /// of size `(h_A·h_B) × (w_A·w_B)`.
+    pub fn kron(&self, other: &Matrix<T>) -> Matrix<T> {
+        let mut tmp = Matrix::new(self.height * other.height, self.width * other.width);
+        for i in 0..self.height {
+            for j in 0..self.width {
+                for k in 0..other.height {
+                    for l in 0..other.width {
+                        tmp[i * other.height + k][j * other.width + l] =
+                            self[i][j].clone() * other[k][l].clone();
+                    }
+                }
+            }
+        }
+        tmp
+    }
+
+    ///# Description:
+    /// The element-wise (Hadamard) product `A ∘ B`.
+    ///
+    /// # Panics:
+    /// Panics if the dimensions differ.
+    pub fn hadamard(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    ///# Description:
+    /// The element-wise quotient `a_ij / b_ij`.
+    ///
+    /// # Panics:
+    /// Panics if the dimensions differ, and like the scalar division for zero entries of `B`.
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Matrix<T> {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    fn zip_with(&self, other: &Matrix<T>, f: impl Fn(T, T) -> T) -> Matrix<T> {
+        if self.height != other.height || self.width != other.width {
+            panic!("Matrix dimensions do not match");
+        }
+        let mut tmp = self.clone();
+        for i in 0..self.height {
+            for j in 0..self.width {
+                tmp[i][j] = f(self[i][j].clone(), other[i][j].clone());
+            }
+        }
+        tmp
+    }
+
+    ///# Description:
+    /// The outer product `u·vᵀ` of two vectors, a matrix of height `u.len()` and width `v.len()`.
+    pub fn outer(u: &[T], v: &[T]) -> Matrix<T> {
+        Vector::from(u).outer(&Vector::from(v))
+    }
+
+    ///# Description:
+    /// The commutator `[A, B] = A·B - B·A`, which is zero if the matrices commute.
+    ///
+    /// # Panics:
+    /// Panics if the matrices are not square of the same size.
+    pub fn commutator(&self, other: &Matrix<T>) -> Matrix<T> {
+        if self.width != self.height || other.width != other.height || self.height != other.height {
+            panic!("Matrix dimensions do not match");
+        }
+        &(self * other) - &(other * self)
+    }
+
+    ///# Description:
+    /// The Khatri-Rao product, the column-wise Kronecker product:
+    /// column `j` of the result is `a_j ⊗ b_j`.
+    ///
+    /// # Panics:
+    /// Panics if the widths differ.
+    pub fn khatri_rao(&self, other: &Matrix<T>) -> Matrix<T> {
+        if self.width != other.width {
+            panic!("Matrix dimensions do not match");
+        }
+        let mut tmp = Matrix::new(self.height * other.height, self.width);
+        for i in 0..self.height {
+            for k in 0..other.height {
+                for j in 0..self.width {
+                    tmp[i * other.height + k][j] = self[i][j].clone() * other[k][j].clone();
+                }
+            }
+        }
+        tmp
+    }
+
+    ///# Description:
+    /// Stack the columns into a single vector of length `height·width` (column-major order).
+    /// This is the `vec` operator with `vec(A·X·B) = (Bᵀ ⊗ A)·vec(X)`.
+    pub fn vec(&self) -> Vector<T> {
+        (0..self.width)
+            .flat_map(|j| (0..self.height).map(move |i| self[i][j].clone()))
+            .collect()
+    }
+
+    ///# Description:
+    /// The inverse of [`Matrix::vec`], fill a `height × width` matrix column by column.
+    ///
+    /// # Panics:
+    /// Panics if the length of `v` is not `height·width`.
+    pub fn unvec(v: &[T], height: usize, width: usize) -> Matrix<T> {
+        if v.len() != height * width {
+            panic!("Vector length does not match the matrix dimensions");
+        }
+        let mut tmp = Matrix::new(height, width);
+        for (k, x) in v.iter().enumerate() {
+            tmp[k % height][k / height] = x.clone();
+        }
+        tmp
+    }
+}
@@ -0,0 +1,290 @@
+use super::{BigInt, ExactScalar, Scalar};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// # Rational
+/// An exact fraction `num / den` of two [`BigInt`]s.
+///
+/// Rationals are always kept reduced with a positive denominator,
+/// so two equal values have the same representation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: BigInt,
+    den: BigInt,
+}
+
+/// The largest power of ten a decimal literal may scale by, e.g. `1e10000`.
+/// Larger exponents are rejected, as the exact value would not fit in memory.
+pub const MAX_DECIMAL_EXPONENT: u32 = 10_000;
+
+/// Error returned when a string cannot be parsed as a [`Rational`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRationalError;
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rational literal")
+    }
+}
+
+impl Rational {
+    ///# Description:
+    /// Create a new reduced fraction `num / den`.
+    ///
+    /// # Panics:
+    /// Panics if the denominator is zero.
+    pub fn new(num: BigInt, den: BigInt) -> Rational {
+        if den.is_zero() {
+            panic!("Denominator must not be zero");
+        }
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (&num / &g, &den / &g);
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+        Rational { num, den }
+    }
+
+    ///# Description:
+    /// Create a rational from an integer.
+    pub fn from_integer(n: BigInt) -> Rational {
+        Rational {
+            num: n,
+            den: BigInt::one(),
+        }
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.num
+    }
+
+    pub fn denom(&self) -> &BigInt {
+        &self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den.is_one()
+    }
+
+    ///# Description:
+    /// The multiplicative inverse `den / num`.
+    ///
+    /// # Panics:
+    /// Panics if the value is zero.
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.den.clone(), self.num.clone())
+    }
+
+    ///# Description:
+    /// Convert a finite `f64` exactly, every binary float is a fraction with a power of two as denominator.
+    /// Returns `None` for NaN and infinity.
+    pub fn from_f64(v: f64) -> Option<Rational> {
+        if !v.is_finite() {
+            return None;
+        }
+        let bits = v.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i32;
+        let frac = bits & ((1 << 52) - 1);
+        let (mantissa, exp) = if exp == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), exp - 1075)
+        };
+        let mut num = BigInt::from(mantissa);
+        if v.is_sign_negative() {
+            num = -num;
+        }
+        let two = BigInt::from(2_i64);
+        let scale = (0..exp.unsigned_abs()).fold(BigInt::one(), |acc, _| &acc * &two);
+        if exp >= 0 {
+            Some(Rational::from_integer(num * scale))
+        } else {
+            Some(Rational::new(num, scale))
+        }
+    }
+
+    ///# Description:
+    /// Convert to the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        let (n, ne) = self.num.to_f64_exp();
+        let (d, de) = self.den.to_f64_exp();
+        n / d * 2_f64.powi(ne - de)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::from_integer(BigInt::from(n))
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, rhs: Rational) -> Self::Output {
+        if self.den == rhs.den {
+            return Rational::new(self.num + rhs.num, self.den);
+        }
+        Rational::new(
+            &self.num * &rhs.den + &rhs.num * &self.den,
+            self.den * rhs.den,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Rational) -> Self::Output {
+        Rational::new(self.num * rhs.num, self.den * rhs.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, rhs: Rational) -> Self::Output {
+        if rhs.num.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        Rational::new(self.num * rhs.den, self.den * rhs.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Self::Output {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl ExactScalar for Rational {}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from(0)
+    }
+
+    fn one() -> Self {
+        Rational::from(1)
+    }
+
+    fn from_f64(v: f64) -> Self {
+        Rational::from_f64(v).expect("Cannot convert a non finite number to a rational")
+    }
+
+    fn abs(&self) -> f64 {
+        self.to_f64().abs()
+    }
+
+    fn epsilon() -> f64 {
+        0.0
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+/// Parses integers (`-3`), fractions (`7/3`) and decimals (`1.25`, `2.5e-3`) exactly.
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((num, den)) = s.split_once('/') {
+            let num: BigInt = num.trim().parse().map_err(|_| ParseRationalError)?;
+            let den: BigInt = den.trim().parse().map_err(|_| ParseRationalError)?;
+            if den.is_zero() {
+                return Err(ParseRationalError);
+            }
+            return Ok(Rational::new(num, den));
+        }
+
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], s[i + 1..].parse::<i32>().map_err(|_| ParseRationalError)?),
+            None => (s, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int.trim_start_matches(['-', '+']).is_empty() && frac.is_empty() {
+            return Err(ParseRationalError);
+        }
+        if !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseRationalError);
+        }
+        let digits = match int {
+            "" | "-" | "+" => format!("{}0{}", int, frac),
+            _ => format!("{}{}", int, frac),
+        };
+        let num: BigInt = digits.parse().map_err(|_| ParseRationalError)?;
+        let exp = i32::try_from(frac.len())
+            .ok()
+            .and_then(|len| exp.checked_sub(len))
+            .filter(|e| e.unsigned_abs() <= MAX_DECIMAL_EXPONENT)
+            .ok_or(ParseRationalError)?;
+        let scale = pow10(exp.unsigned_abs());
+        if exp >= 0 {
+            Ok(Rational::from_integer(num * scale))
+        } else {
+            Ok(Rational::new(num, scale))
+        }
+    }
+}
+
+/// `10^k` by repeated squaring.
+fn pow10(mut k: u32) -> BigInt {
+    let mut base = BigInt::from(10_i64);
+    let mut result = BigInt::one();
+    while k > 0 {
+        if k % 2 == 1 {
+            result = &result * &base;
+        }
+        base = &base * &base;
+        k /= 2;
+    }
+    result
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,378 @@
+use super::{Matrix, Scalar};
+use std::fmt;
+
+/// # Row operation
+/// An elementary row operation. Rows are counted from 0,
+/// the [`Display`](fmt::Display) form counts from 1 like textbooks do, e.g. `R2 ← R2 − 2·R1`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowOp<T = f64> {
+    /// Exchange two rows.
+    Swap(usize, usize),
+    /// Multiply a row by a non-zero factor.
+    Scale { row: usize, factor: T },
+    /// Add `factor` times the `source` row to a different `target` row.
+    AddMultiple { target: usize, source: usize, factor: T },
+}
+
+impl<T: Scalar> RowOp<T> {
+    ///# Description:
+    /// Apply the operation to the matrix in place.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds.
+    pub fn apply(&self, m: &mut Matrix<T>) {
+        match self {
+            RowOp::Swap(a, b) => m.swap_rows(*a, *b),
+            RowOp::Scale { row, factor } => m.scale_row(*row, factor.clone()),
+            RowOp::AddMultiple { target, source, factor } => m.add_row_multiple(*source, *target, factor.clone()),
+        }
+    }
+
+    ///# Description:
+    /// The operation that undoes this one,
+    /// `None` for a scaling by zero or a row added to itself, which are not elementary.
+    pub fn inverse(&self) -> Option<RowOp<T>> {
+        match self {
+            RowOp::Swap(a, b) => Some(RowOp::Swap(*a, *b)),
+            RowOp::Scale { row, factor } => {
+                if factor.is_zero() {
+                    return None;
+                }
+                Some(RowOp::Scale { row: *row, factor: T::one() / factor.clone() })
+            }
+            RowOp::AddMultiple { target, source, .. } if target == source => None,
+            RowOp::AddMultiple { target, source, factor } => Some(RowOp::AddMultiple {
+                target: *target,
+                source: *source,
+                factor: -factor.clone(),
+            }),
+        }
+    }
+
+    ///# Description:
+    /// The `n × n` elementary matrix `E` of the operation, `E·A` applies it to `A`.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds.
+    pub fn matrix(&self, n: usize) -> Matrix<T> {
+        let mut e = Matrix::identity(n);
+        self.apply(&mut e);
+        e
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// Swap two rows, `None` instead of a panic if a row is out of bounds.
+    pub fn checked_swap_rows(&mut self, row: usize, other: usize) -> Option<()> {
+        if row >= self.height || other >= self.height {
+            return None;
+        }
+        self.data.swap(row, other);
+        Some(())
+    }
+
+    ///# Description:
+    /// Multiply a row by a factor.
+    ///
+    /// # Panics:
+    /// Panics if the row is out of bounds.
+    pub fn scale_row(&mut self, row: usize, factor: T) {
+        check_row(self, row);
+        for x in self.data[row].iter_mut() {
+            *x = x.clone() * factor.clone();
+        }
+    }
+
+    ///# Description:
+    /// Add `factor` times the row `src` to the row `dst`, in place without allocating.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds or `src == dst`,
+    /// adding a row to itself is not an elementary operation.
+    pub fn add_row_multiple(&mut self, src: usize, dst: usize, factor: T) {
+        check_row(self, src);
+        check_row(self, dst);
+        check_distinct(src, dst);
+        let (source, target) = if src < dst {
+            let (a, b) = self.data.split_at_mut(dst);
+            (&a[src], &mut b[0])
+        } else {
+            let (a, b) = self.data.split_at_mut(src);
+            (&b[0], &mut a[dst])
+        };
+        for (t, s) in target.iter_mut().zip(source) {
+            *t = t.clone() + factor.clone() * s.clone();
+        }
+    }
+
+    ///# Description:
+    /// Swap two columns.
+    ///
+    /// # Panics:
+    /// Panics if a column is out of bounds.
+    pub fn swap_columns(&mut self, col: usize, other: usize) {
+        check_column(self, col);
+        check_column(self, other);
+        for row in self.data.iter_mut() {
+            row.swap(col, other);
+        }
+    }
+
+    ///# Description:
+    /// Swap two columns, `None` instead of a panic if a column is out of bounds.
+    pub fn checked_swap_columns(&mut self, col: usize, other: usize) -> Option<()> {
+        if col >= self.width || other >= self.width {
+            return None;
+        }
+        self.swap_columns(col, other);
+        Some(())
+    }
+
+    ///# Description:
+    /// Multiply a column by a factor.
+    ///
+    /// # Panics:
+    /// Panics if the column is out of bounds.
+    pub fn scale_column(&mut self, col: usize, factor: T) {
+        check_column(self, col);
+        for row in self.data.iter_mut() {
+            row[col] = row[col].clone() * factor.clone();
+        }
+    }
+
+    ///# Description:
+    /// Add `factor` times the column `src` to the column `dst`.
+    ///
+    /// # Panics:
+    /// Panics if a column is out of bounds or `src == dst`.
+    pub fn add_column_multiple(&mut self, src: usize, dst: usize, factor: T) {
+        check_column(self, src);
+        check_column(self, dst);
+        check_distinct(src, dst);
+        for row in self.data.iter_mut() {
+            row[dst] = row[dst].clone() + factor.clone() * row[src].clone();
+        }
+    }
+
+    ///# Description:
+    /// The `n × n` elementary matrix that swaps rows `a` and `b` from the left, `E·A`,
+    /// and columns `a` and `b` from the right, `A·E`.
+    ///
+    /// # Panics:
+    /// Panics if an index is not smaller than `n`.
+    pub fn elementary_swap(n: usize, a: usize, b: usize) -> Matrix<T> {
+        RowOp::Swap(a, b).matrix(n)
+    }
+
+    ///# Description:
+    /// The `n × n` elementary matrix that scales row `i` from the left, `E·A`,
+    /// and column `i` from the right, `A·E`.
+    ///
+    /// # Panics:
+    /// Panics if `i` is not smaller than `n`.
+    pub fn elementary_scale(n: usize, i: usize, factor: T) -> Matrix<T> {
+        RowOp::Scale { row: i, factor }.matrix(n)
+    }
+
+    ///# Description:
+    /// The `n × n` elementary matrix with `factor` at `(dst, src)`.
+    /// From the left, `E·A` adds `factor` times row `src` to row `dst`.
+    /// From the right, `A·E` adds `factor` times column `dst` to column `src`,
+    /// so `add_column_multiple(src, dst, factor)` is `A·Eᵀ`.
+    ///
+    /// # Panics:
+    /// Panics if an index is not smaller than `n` or `src == dst`.
+    pub fn elementary_add(n: usize, src: usize, dst: usize, factor: T) -> Matrix<T> {
+        RowOp::AddMultiple { target: dst, source: src, factor }.matrix(n)
+    }
+}
+
+/// # Row operation log
+/// A sequence of row operations that can be replayed on another matrix or undone.
+///
+/// ```
+/// use lgs_lib::matrix::matrix::{Matrix, RowOp, RowOpLog};
+///
+/// let mut m = Matrix::from_data(vec![vec![2.0, 1.0], vec![4.0, 5.0]]);
+/// let mut log = RowOpLog::new();
+/// log.apply(RowOp::AddMultiple { target: 1, source: 0, factor: -2.0 }, &mut m);
+/// assert_eq!(m.get_data(), vec![vec![2.0, 1.0], vec![0.0, 3.0]]);
+///
+/// log.undo(&mut m);
+/// assert_eq!(m.get_data(), vec![vec![2.0, 1.0], vec![4.0, 5.0]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowOpLog<T = f64> {
+    ops: Vec<RowOp<T>>,
+}
+
+impl<T> Default for RowOpLog<T> {
+    fn default() -> Self {
+        RowOpLog { ops: vec![] }
+    }
+}
+
+impl<T> RowOpLog<T> {
+    pub fn new() -> RowOpLog<T> {
+        RowOpLog::default()
+    }
+
+    /// Record an operation without applying it.
+    pub fn push(&mut self, op: RowOp<T>) {
+        self.ops.push(op);
+    }
+
+    /// The recorded operations, oldest first.
+    pub fn ops(&self) -> &[RowOp<T>] {
+        &self.ops
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+impl<T: Scalar> RowOpLog<T> {
+    ///# Description:
+    /// Apply the operation to the matrix and record it.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds, nothing is recorded then.
+    pub fn apply(&mut self, op: RowOp<T>, m: &mut Matrix<T>) {
+        op.apply(m);
+        self.ops.push(op);
+    }
+
+    ///# Description:
+    /// Apply all recorded operations in order to the matrix.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds.
+    pub fn replay(&self, m: &mut Matrix<T>) {
+        for op in &self.ops {
+            op.apply(m);
+        }
+    }
+
+    ///# Description:
+    /// The log that undoes this one, the inverse operations in reverse order.
+    /// `None` if an operation is not elementary, see [`RowOp::inverse`].
+    pub fn inverse(&self) -> Option<RowOpLog<T>> {
+        let ops = self.ops.iter().rev().map(RowOp::inverse).collect::<Option<_>>()?;
+        Some(RowOpLog { ops })
+    }
+
+    ///# Description:
+    /// Undo all recorded operations on the matrix they were applied to.
+    /// The log itself is unchanged, the matrix too if it returns `None`
+    /// because an operation is not elementary.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds.
+    pub fn undo(&self, m: &mut Matrix<T>) -> Option<()> {
+        self.inverse()?.replay(m);
+        Some(())
+    }
+
+    ///# Description:
+    /// Undo the last operation and remove it from the log.
+    /// Returns it, or `None` if the log is empty or it is not elementary, which is then kept.
+    ///
+    /// # Panics:
+    /// Panics if a row is out of bounds.
+    pub fn undo_last(&mut self, m: &mut Matrix<T>) -> Option<RowOp<T>> {
+        self.ops.last()?.inverse()?.apply(m);
+        self.ops.pop()
+    }
+
+    ///# Description:
+    /// The product `E_k⋯E_1` of the elementary matrices of all operations,
+    /// `E·A` has the same effect as [`RowOpLog::replay`].
+    ///
+    /// # Panics:
+    /// Panics if a row is not smaller than `n`.
+    pub fn matrix(&self, n: usize) -> Matrix<T> {
+        let mut e = Matrix::identity(n);
+        self.replay(&mut e);
+        e
+    }
+}
+
+impl<T: fmt::Display> RowOp<T> {
+    ///# Description:
+    /// The operation as LaTeX, e.g. `R_2 \leftarrow R_2 - 2 R_1`.
+    pub fn to_latex(&self) -> String {
+        match self {
+            RowOp::Swap(a, b) => format!("R_{{{}}} \\leftrightarrow R_{{{}}}", a + 1, b + 1),
+            RowOp::Scale { row, factor } => {
+                format!("R_{{{0}}} \\leftarrow {1} R_{{{0}}}", row + 1, latex_value(&factor.to_string()))
+            }
+            RowOp::AddMultiple { target, source, factor } => {
+                let (sign, value) = split_sign(factor.to_string());
+                let value = if value == "1" { String::new() } else { latex_value(&value) + " " };
+                format!(
+                    "R_{{{0}}} \\leftarrow R_{{{0}}} {1} {2}R_{{{3}}}",
+                    target + 1,
+                    if sign { "-" } else { "+" },
+                    value,
+                    source + 1
+                )
+            }
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RowOp<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOp::Swap(a, b) => write!(f, "R{} ↔ R{}", a + 1, b + 1),
+            RowOp::Scale { row, factor } => write!(f, "R{0} ← {1}·R{0}", row + 1, factor),
+            RowOp::AddMultiple { target, source, factor } => {
+                let (sign, value) = split_sign(factor.to_string());
+                let sign = if sign { "−" } else { "+" };
+                let value = if value == "1" { String::new() } else { value + "·" };
+                write!(f, "R{0} ← R{0} {1} {2}R{3}", target + 1, sign, value, source + 1)
+            }
+        }
+    }
+}
+
+fn check_row<T>(m: &Matrix<T>, row: usize) {
+    if row >= m.height {
+        panic!("Row or column index out of bounds");
+    }
+}
+
+fn check_distinct(src: usize, dst: usize) {
+    if src == dst {
+        panic!("Source and target must differ");
+    }
+}
+
+fn check_column<T>(m: &Matrix<T>, col: usize) {
+    if col >= m.width {
+        panic!("Row or column index out of bounds");
+    }
+}
+
+/// Split a leading minus sign off a simple value. Compound values like complex numbers keep it.
+fn split_sign(text: String) -> (bool, String) {
+    match text.strip_prefix('-') {
+        Some(rest) if !rest.contains(['+', '-']) => (true, rest.to_string()),
+        _ => (false, text),
+    }
+}
+
+/// Write a value as LaTeX, fractions like `-1/2` become `-\frac{1}{2}`.
+pub(crate) fn latex_value(text: &str) -> String {
+    let (sign, rest) = split_sign(text.to_string());
+    match rest.split_once('/') {
+        Some((num, den)) => format!("{}\\frac{{{}}}{{{}}}", if sign { "-" } else { "" }, num, den),
+        None => text.to_string(),
+    }
+}
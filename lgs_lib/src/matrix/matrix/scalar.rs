@@ -0,0 +1,147 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// # Scalar
+/// The element type of a [`Matrix`](super::Matrix).
+///
+/// A scalar has to behave like a field: it can be added, subtracted,
+/// multiplied and divided, and provides the neutral elements `zero` and `one`.
+/// `abs` and `epsilon` are used by the elimination routines to choose pivots
+/// and to decide when a value is considered to be zero.
+/// Scalars are `Send + Sync`, so matrices can be processed on several threads.
+pub trait Scalar:
+    Clone
+    + Send
+    + Sync
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Convert a real number into the scalar type.
+    /// Exact types convert the binary value exactly, finite fields round to the nearest integer.
+    fn from_f64(v: f64) -> Self;
+
+    /// The absolute value (or modulus) of the scalar.
+    fn abs(&self) -> f64;
+
+    /// Values with an absolute value at or below `epsilon` are treated as zero.
+    fn epsilon() -> f64;
+
+    /// Check whether the value is zero within the tolerance of `epsilon`.
+    fn is_zero(&self) -> bool {
+        self.abs() <= Self::epsilon()
+    }
+
+    /// The complex conjugate, which is the value itself for real scalars.
+    fn conj(&self) -> Self {
+        self.clone()
+    }
+
+    /// The kernel of the matrix product, `y[i] = y[i] + a * x[i]` for every `i`.
+    /// The floating point types override it with a vectorized version.
+    fn axpy(y: &mut [Self], a: &Self, x: &[Self]) {
+        for (y, x) in y.iter_mut().zip(x) {
+            *y = y.clone() + a.clone() * x.clone();
+        }
+    }
+}
+
+/// # Exact scalar
+/// Scalars whose arithmetic is exact, such as rationals and finite fields.
+/// Zero tests are decisive, so ranks and linear dependencies can be computed reliably.
+pub trait ExactScalar: Scalar {}
+
+macro_rules! impl_scalar_for_float {
+    ($($t:ty => $simd:path),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn from_f64(v: f64) -> Self {
+                    v as $t
+                }
+
+                fn abs(&self) -> f64 {
+                    <$t>::abs(*self) as f64
+                }
+
+                fn epsilon() -> f64 {
+                    <$t>::EPSILON as f64
+                }
+
+                fn axpy(y: &mut [Self], a: &Self, x: &[Self]) {
+                    $simd(y, *a, x);
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_for_float!(f32 => axpy_f32, f64 => axpy_f64);
+
+// The lanes are multiplied and added separately, without fused multiply-add,
+// so the vectorized kernels round exactly like the plain loop.
+macro_rules! impl_float_axpy {
+    ($name:ident, $t:ty, $lanes:expr, $avx:ident, $load:ident, $set1:ident, $mul:ident, $add:ident, $store:ident) => {
+        fn $name(y: &mut [$t], a: $t, x: &[$t]) {
+            let n = y.len().min(x.len());
+            let (y, x) = (&mut y[..n], &x[..n]);
+
+            #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+            if is_x86_feature_detected!("avx") {
+                // SAFETY: AVX support was checked at runtime.
+                unsafe { $avx(y, a, x) };
+                return;
+            }
+
+            // Fixed size chunks, which the compiler vectorizes with the baseline instruction set.
+            let mut ys = y.chunks_exact_mut($lanes);
+            let mut xs = x.chunks_exact($lanes);
+            for (y, x) in (&mut ys).zip(&mut xs) {
+                for k in 0..$lanes {
+                    y[k] += a * x[k];
+                }
+            }
+            for (y, x) in ys.into_remainder().iter_mut().zip(xs.remainder()) {
+                *y += a * x;
+            }
+        }
+
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx")]
+        unsafe fn $avx(y: &mut [$t], a: $t, x: &[$t]) {
+            use std::arch::x86_64::*;
+            let n = y.len();
+            let av = $set1(a);
+            let mut i = 0;
+            while i + $lanes <= n {
+                let xv = $load(x.as_ptr().add(i));
+                let yv = $load(y.as_ptr().add(i));
+                $store(y.as_mut_ptr().add(i), $add(yv, $mul(av, xv)));
+                i += $lanes;
+            }
+            for k in i..n {
+                y[k] += a * x[k];
+            }
+        }
+    };
+}
+
+impl_float_axpy!(axpy_f64, f64, 4, axpy_f64_avx, _mm256_loadu_pd, _mm256_set1_pd, _mm256_mul_pd, _mm256_add_pd, _mm256_storeu_pd);
+impl_float_axpy!(axpy_f32, f32, 8, axpy_f32_avx, _mm256_loadu_ps, _mm256_set1_ps, _mm256_mul_ps, _mm256_add_ps, _mm256_storeu_ps);
@@ -0,0 +1,342 @@
+use super::Matrix;
+use std::fmt;
+use std::ops::{self, Index, IndexMut};
+
+/// # SMatrix
+/// A stack allocated `R×C` matrix of `f64` with its dimensions fixed at compile time.
+///
+/// Meant for small geometry matrices, where the heap allocations of [`Matrix`] dominate.
+/// Multiplying two matrices of incompatible shape does not compile,
+/// instead of panicking at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<const R: usize, const C: usize> {
+    data: [[f64; C]; R],
+}
+
+/// Error returned when a [`Matrix`] is converted into an [`SMatrix`] of different dimensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a {}x{} matrix, found {}x{}",
+            self.expected.0, self.expected.1, self.found.0, self.found.1
+        )
+    }
+}
+
+pub type SMatrix2 = SMatrix<2, 2>;
+pub type SMatrix3 = SMatrix<3, 3>;
+pub type SMatrix4 = SMatrix<4, 4>;
+
+impl<const R: usize, const C: usize> SMatrix<R, C> {
+    ///# Description:
+    /// Create a new matrix from an array of rows.
+    pub const fn new(data: [[f64; C]; R]) -> SMatrix<R, C> {
+        SMatrix { data }
+    }
+
+    ///# Description:
+    /// Create a new matrix with all values set to 0.0.
+    pub const fn zeros() -> SMatrix<R, C> {
+        SMatrix { data: [[0.0; C]; R] }
+    }
+
+    pub fn get_data(&self) -> [[f64; C]; R] {
+        self.data
+    }
+
+    /// # Description:
+    /// Create a new matrix that is the transposed of the current matrix.
+    pub fn transpose(&self) -> SMatrix<C, R> {
+        let mut tmp = SMatrix::<C, R>::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                tmp[j][i] = self[i][j];
+            }
+        }
+        tmp
+    }
+
+    /// # Description:
+    /// Multiply the matrix with a column vector.
+    pub fn mul_vec(&self, v: &[f64; C]) -> [f64; R] {
+        let mut res = [0.0; R];
+        for (r, row) in res.iter_mut().zip(self.data.iter()) {
+            *r = row.iter().zip(v).map(|(a, b)| a * b).sum();
+        }
+        res
+    }
+}
+
+impl<const N: usize> SMatrix<N, N> {
+    ///# Description:
+    /// Create the N×N identity matrix.
+    pub fn identity() -> SMatrix<N, N> {
+        let mut m = SMatrix::zeros();
+        for i in 0..N {
+            m[i][i] = 1.0;
+        }
+        m
+    }
+
+    /// # Description:
+    /// The sum of the main diagonal.
+    pub fn trace(&self) -> f64 {
+        (0..N).map(|i| self[i][i]).sum()
+    }
+}
+
+impl SMatrix<1, 1> {
+    ///# Description:
+    /// Calculates the determinant of the matrix.
+    pub fn det(&self) -> f64 {
+        self[0][0]
+    }
+
+    /// # Description:
+    /// Calculate the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<SMatrix<1, 1>> {
+        if self[0][0] == 0.0 {
+            return None;
+        }
+        Some(SMatrix::new([[1.0 / self[0][0]]]))
+    }
+}
+
+impl SMatrix<2, 2> {
+    ///# Description:
+    /// Calculates the determinant of the matrix in closed form.
+    pub fn det(&self) -> f64 {
+        self[0][0] * self[1][1] - self[0][1] * self[1][0]
+    }
+
+    /// # Description:
+    /// Calculate the inverse in closed form, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<SMatrix<2, 2>> {
+        let det = self.det();
+        if det == 0.0 {
+            return None;
+        }
+        let m = self;
+        Some(SMatrix::new([[m[1][1], -m[0][1]], [-m[1][0], m[0][0]]]) * (1.0 / det))
+    }
+}
+
+impl SMatrix<3, 3> {
+    ///# Description:
+    /// Calculates the determinant of the matrix in closed form (rule of Sarrus).
+    pub fn det(&self) -> f64 {
+        let m = self;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// # Description:
+    /// Calculate the inverse from the adjugate, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<SMatrix<3, 3>> {
+        let det = self.det();
+        if det == 0.0 {
+            return None;
+        }
+        let m = self;
+        let adj = SMatrix::new([
+            [
+                m[1][1] * m[2][2] - m[1][2] * m[2][1],
+                m[0][2] * m[2][1] - m[0][1] * m[2][2],
+                m[0][1] * m[1][2] - m[0][2] * m[1][1],
+            ],
+            [
+                m[1][2] * m[2][0] - m[1][0] * m[2][2],
+                m[0][0] * m[2][2] - m[0][2] * m[2][0],
+                m[0][2] * m[1][0] - m[0][0] * m[1][2],
+            ],
+            [
+                m[1][0] * m[2][1] - m[1][1] * m[2][0],
+                m[0][1] * m[2][0] - m[0][0] * m[2][1],
+                m[0][0] * m[1][1] - m[0][1] * m[1][0],
+            ],
+        ]);
+        Some(adj * (1.0 / det))
+    }
+}
+
+impl SMatrix<4, 4> {
+    /// The 2×2 minors of the upper two rows (`s`) and lower two rows (`c`),
+    /// from which the determinant and adjugate of a 4×4 matrix are built.
+    fn minors(&self) -> ([f64; 6], [f64; 6]) {
+        let m = self;
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        (s, c)
+    }
+
+    ///# Description:
+    /// Calculates the determinant of the matrix in closed form (Laplace expansion in 2×2 minors).
+    pub fn det(&self) -> f64 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// # Description:
+    /// Calculate the inverse from the adjugate, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<SMatrix<4, 4>> {
+        let (s, c) = self.minors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == 0.0 {
+            return None;
+        }
+        let m = self;
+        let adj = SMatrix::new([
+            [
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ],
+            [
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ],
+            [
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ],
+            [
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ],
+        ]);
+        Some(adj * (1.0 / det))
+    }
+}
+
+impl<const R: usize, const C: usize> Default for SMatrix<R, C> {
+    fn default() -> Self {
+        SMatrix::zeros()
+    }
+}
+
+impl<const R: usize, const C: usize> ops::Add for SMatrix<R, C> {
+    type Output = SMatrix<R, C>;
+    fn add(mut self, m: SMatrix<R, C>) -> Self::Output {
+        for i in 0..R {
+            for j in 0..C {
+                self[i][j] += m[i][j];
+            }
+        }
+        self
+    }
+}
+
+impl<const R: usize, const C: usize> ops::Sub for SMatrix<R, C> {
+    type Output = SMatrix<R, C>;
+    fn sub(mut self, m: SMatrix<R, C>) -> Self::Output {
+        for i in 0..R {
+            for j in 0..C {
+                self[i][j] -= m[i][j];
+            }
+        }
+        self
+    }
+}
+
+impl<const R: usize, const C: usize> ops::Mul<f64> for SMatrix<R, C> {
+    type Output = SMatrix<R, C>;
+    fn mul(mut self, v: f64) -> Self::Output {
+        for row in self.data.iter_mut() {
+            for x in row.iter_mut() {
+                *x *= v;
+            }
+        }
+        self
+    }
+}
+
+/// `R×C · C×K = R×K`, the inner dimensions have to agree at compile time.
+impl<const R: usize, const C: usize, const K: usize> ops::Mul<SMatrix<C, K>> for SMatrix<R, C> {
+    type Output = SMatrix<R, K>;
+    fn mul(self, m: SMatrix<C, K>) -> Self::Output {
+        let mut tmp = SMatrix::<R, K>::zeros();
+        for i in 0..R {
+            for k in 0..C {
+                let a = self[i][k];
+                for j in 0..K {
+                    tmp[i][j] += a * m[k][j];
+                }
+            }
+        }
+        tmp
+    }
+}
+
+impl<const R: usize, const C: usize> Index<usize> for SMatrix<R, C> {
+    type Output = [f64; C];
+    fn index(&self, index: usize) -> &[f64; C] {
+        &self.data[index]
+    }
+}
+
+impl<const R: usize, const C: usize> IndexMut<usize> for SMatrix<R, C> {
+    fn index_mut(&mut self, i: usize) -> &mut [f64; C] {
+        &mut self.data[i]
+    }
+}
+
+impl<const R: usize, const C: usize> From<SMatrix<R, C>> for Matrix<f64> {
+    fn from(m: SMatrix<R, C>) -> Self {
+        Matrix::from_data(m.data.iter().map(|row| row.to_vec()).collect())
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<&Matrix<f64>> for SMatrix<R, C> {
+    type Error = DimensionMismatch;
+
+    fn try_from(m: &Matrix<f64>) -> Result<Self, Self::Error> {
+        if m.height != R || m.width != C {
+            return Err(DimensionMismatch {
+                expected: (R, C),
+                found: (m.height, m.width),
+            });
+        }
+        let mut tmp = SMatrix::zeros();
+        for i in 0..R {
+            tmp[i].copy_from_slice(&m[i]);
+        }
+        Ok(tmp)
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<Matrix<f64>> for SMatrix<R, C> {
+    type Error = DimensionMismatch;
+
+    fn try_from(m: Matrix<f64>) -> Result<Self, Self::Error> {
+        SMatrix::try_from(&m)
+    }
+}
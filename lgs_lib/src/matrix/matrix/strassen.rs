@@ -0,0 +1,117 @@
+use super::{Matrix, Scalar};
+
+/// Below this size the recursion stops and the blocked product takes over.
+/// Strassen saves one of eight multiplications per level but costs 18 additions,
+/// which only pays off for fairly large blocks.
+pub const STRASSEN_THRESHOLD: usize = 128;
+
+impl<T: Scalar> Matrix<T> {
+    ///# Description:
+    /// Multiply with Strassen's algorithm, recursing down to blocks of [`STRASSEN_THRESHOLD`].
+    /// See [`Matrix::mul_strassen_with_threshold`].
+    ///
+    /// # Panics:
+    /// Panics if the width of `self` does not match the height of `m`.
+    pub fn mul_strassen(&self, m: &Matrix<T>) -> Matrix<T> {
+        self.mul_strassen_with_threshold(m, STRASSEN_THRESHOLD)
+    }
+
+    ///# Description:
+    /// Multiply with Strassen's algorithm, which needs 7 instead of 8 products
+    /// of half the size per level.
+    /// Blocks of at most `threshold` rows and columns are multiplied with [`Matrix::mul_blocked`].
+    ///
+    /// Odd sized and mildly rectangular matrices are padded with zeros, which do not change the product.
+    /// Strongly rectangular products are split into square tiles of the smallest dimension,
+    /// and fall back to the blocked product if that dimension is at most `threshold`.
+    /// For floating point types the result differs from the classical product by rounding,
+    /// exact types give the same result.
+    ///
+    /// # Arguments:
+    /// - m: `&Matrix<T>`, the right hand side.
+    /// - threshold: usize, the block size where the recursion stops, at least 1.
+    ///
+    /// # Panics:
+    /// Panics if the width of `self` does not match the height of `m`.
+    pub fn mul_strassen_with_threshold(&self, m: &Matrix<T>, threshold: usize) -> Matrix<T> {
+        if self.width != m.height {
+            panic!("Matrix dimensions do not match");
+        }
+        let threshold = threshold.max(1);
+        let n = self.height.max(self.width).max(m.width);
+        let s = self.height.min(self.width).min(m.width);
+        if s <= threshold {
+            // small, or so thin that the classical product is cheaper than any padding
+            return self.mul_blocked(m);
+        }
+        if n <= 2 * s {
+            let a = padded(self, n, n);
+            let b = padded(m, n, n);
+            let c = strassen(&a, &b, threshold);
+            return padded(&c, self.height, m.width);
+        }
+
+        // strongly rectangular, multiply square tiles of the smallest dimension
+        let mut c = Matrix::new(self.height, m.width);
+        for i in (0..self.height).step_by(s) {
+            for j in (0..m.width).step_by(s) {
+                let mut tile = Matrix::new(s, s);
+                for k in (0..self.width).step_by(s) {
+                    let a = padded(&self.slice(i..(i + s).min(self.height), k..(k + s).min(self.width)), s, s);
+                    let b = padded(&m.slice(k..(k + s).min(m.height), j..(j + s).min(m.width)), s, s);
+                    tile = &tile + &strassen(&a, &b, threshold);
+                }
+                c.set_block(i, j, &padded(&tile, s.min(self.height - i), s.min(m.width - j)));
+            }
+        }
+        c
+    }
+}
+
+/// Multiply two square matrices of the same size.
+fn strassen<T: Scalar>(a: &Matrix<T>, b: &Matrix<T>, threshold: usize) -> Matrix<T> {
+    let n = a.height;
+    if n <= threshold {
+        return a.mul_blocked(b);
+    }
+    if n % 2 == 1 {
+        let c = strassen(&padded(a, n + 1, n + 1), &padded(b, n + 1, n + 1), threshold);
+        return padded(&c, n, n);
+    }
+
+    let h = n / 2;
+    let [a11, a12, a21, a22] = quadrants(a, h);
+    let [b11, b12, b21, b22] = quadrants(b, h);
+
+    let m1 = strassen(&(&a11 + &a22), &(&b11 + &b22), threshold);
+    let m2 = strassen(&(&a21 + &a22), &b11, threshold);
+    let m3 = strassen(&a11, &(&b12 - &b22), threshold);
+    let m4 = strassen(&a22, &(&b21 - &b11), threshold);
+    let m5 = strassen(&(&a11 + &a12), &b22, threshold);
+    let m6 = strassen(&(&a21 - &a11), &(&b11 + &b12), threshold);
+    let m7 = strassen(&(&a12 - &a22), &(&b21 + &b22), threshold);
+
+    let c11 = &(&m1 + &m4) - &(&m5 - &m7);
+    let c12 = &m3 + &m5;
+    let c21 = &m2 + &m4;
+    let c22 = &(&m1 - &m2) + &(&m3 + &m6);
+
+    Matrix::block(&[&[&c11, &c12], &[&c21, &c22]])
+}
+
+/// Split a square matrix of size `2h` into its four quadrants, row by row.
+fn quadrants<T: Scalar>(m: &Matrix<T>, h: usize) -> [Matrix<T>; 4] {
+    [
+        m.slice(..h, ..h),
+        m.slice(..h, h..),
+        m.slice(h.., ..h),
+        m.slice(h.., h..),
+    ]
+}
+
+/// Copy the matrix into a `height × width` matrix, filling with zeros or cutting off as needed.
+fn padded<T: Scalar>(m: &Matrix<T>, height: usize, width: usize) -> Matrix<T> {
+    let mut tmp = Matrix::new(height, width);
+    tmp.set_block(0, 0, &m.slice(..height.min(m.height), ..width.min(m.width)));
+    tmp
+}
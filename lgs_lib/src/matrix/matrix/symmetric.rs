@@ -0,0 +1,102 @@
+use super::{Matrix, Scalar, Vector};
+
+/// # Symmetric matrix
+/// A square matrix with `a_ij = a_ji`.
+/// The lower triangle is stored packed row by row, which needs `n·(n+1)/2` entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<T = f64> {
+    n: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> SymmetricMatrix<T> {
+    ///# Description:
+    /// Create a zero symmetric matrix of size `n × n`.
+    pub fn new(n: usize) -> SymmetricMatrix<T> {
+        SymmetricMatrix {
+            n,
+            data: vec![T::zero(); n * (n + 1) / 2],
+        }
+    }
+
+    ///# Description:
+    /// Pack a symmetric matrix.
+    /// Returns `None` if the matrix is not square or `a_ij` and `a_ji` differ by more than `T::epsilon()`.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<SymmetricMatrix<T>> {
+        if m.width != m.height {
+            return None;
+        }
+        let mut data = Vec::with_capacity(m.height * (m.height + 1) / 2);
+        for i in 0..m.height {
+            for j in 0..=i {
+                if !(m[i][j].clone() - m[j][i].clone()).is_zero() {
+                    return None;
+                }
+                data.push(m[i][j].clone());
+            }
+        }
+        Some(SymmetricMatrix { n: m.height, data })
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    fn index(&self, i: usize, j: usize) -> usize {
+        if i >= self.n || j >= self.n {
+            panic!("Row or column index out of bounds");
+        }
+        let (i, j) = if i >= j { (i, j) } else { (j, i) };
+        i * (i + 1) / 2 + j
+    }
+
+    /// The entry in row `i` and column `j`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        self.data[self.index(i, j)].clone()
+    }
+
+    /// Set the entries `a_ij` and `a_ji` at once.
+    pub fn set(&mut self, i: usize, j: usize, v: T) {
+        let k = self.index(i, j);
+        self.data[k] = v;
+    }
+
+    ///# Description:
+    /// The product `A·x` in O(n²), reading every stored entry once.
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut y: Vector<T> = Vector::new(self.n);
+        let mut k = 0;
+        for i in 0..self.n {
+            for j in 0..=i {
+                let a = self.data[k].clone();
+                y[i] = y[i].clone() + a.clone() * x[j].clone();
+                if i != j {
+                    y[j] = y[j].clone() + a * x[i].clone();
+                }
+                k += 1;
+            }
+        }
+        y
+    }
+}
+
+impl<T: Scalar> From<SymmetricMatrix<T>> for Matrix<T> {
+    fn from(s: SymmetricMatrix<T>) -> Self {
+        let mut m = Matrix::new(s.n, s.n);
+        for i in 0..s.n {
+            for j in 0..=i {
+                let a = s.get(i, j);
+                m[j][i] = a.clone();
+                m[i][j] = a;
+            }
+        }
+        m
+    }
+}
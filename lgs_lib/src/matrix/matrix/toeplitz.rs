@@ -0,0 +1,155 @@
+use super::{Matrix, Scalar, Vector};
+
+/// # Toeplitz matrix
+/// A square matrix that is constant along every diagonal, `a_ij = t(i - j)`.
+/// It is stored by its first column and first row, which share the corner entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToeplitzMatrix<T = f64> {
+    col: Vec<T>,
+    row: Vec<T>,
+}
+
+impl<T: Scalar> ToeplitzMatrix<T> {
+    ///# Description:
+    /// Create a Toeplitz matrix from its first column and first row.
+    ///
+    /// # Panics:
+    /// Panics if the lengths differ or the first entries are not equal.
+    pub fn new(first_col: Vec<T>, first_row: Vec<T>) -> ToeplitzMatrix<T> {
+        if first_col.len() != first_row.len() {
+            panic!("First row and column must have the same length");
+        }
+        if first_col.first() != first_row.first() {
+            panic!("First row and column must start with the same entry");
+        }
+        ToeplitzMatrix {
+            col: first_col,
+            row: first_row,
+        }
+    }
+
+    ///# Description:
+    /// Create a symmetric Toeplitz matrix, such as an autocorrelation matrix, from its first column.
+    pub fn symmetric(first_col: Vec<T>) -> ToeplitzMatrix<T> {
+        ToeplitzMatrix {
+            row: first_col.clone(),
+            col: first_col,
+        }
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.col.len()
+    }
+
+    pub fn first_col(&self) -> &[T] {
+        &self.col
+    }
+
+    pub fn first_row(&self) -> &[T] {
+        &self.row
+    }
+
+    /// The entry in row `i` and column `j`.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= self.size() || j >= self.size() {
+            panic!("Row or column index out of bounds");
+        }
+        if i >= j {
+            self.col[i - j].clone()
+        } else {
+            self.row[j - i].clone()
+        }
+    }
+
+    ///# Description:
+    /// The product `T·x` in O(n²) without building the dense matrix.
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        let n = self.size();
+        if x.len() != n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        (0..n)
+            .map(|i| {
+                (0..n).fold(T::zero(), |acc, j| acc + self.get(i, j) * x[j].clone())
+            })
+            .collect()
+    }
+
+    ///# Description:
+    /// Solve `T·x = b` with the Levinson recursion in O(n²).
+    ///
+    /// The recursion solves the leading `k × k` systems for growing `k`,
+    /// so it returns `None` if one of them is singular, even if the whole matrix is not.
+    /// It does not pivot, and is stable for symmetric positive definite
+    /// and diagonally dominant matrices.
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match.
+    pub fn levinson(&self, b: &[T]) -> Option<Vector<T>> {
+        let n = self.size();
+        if b.len() != n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        if n == 0 {
+            return Some(Vector::from_data(vec![]));
+        }
+        if self.col[0].is_zero() {
+            return None;
+        }
+
+        // forward and backward vectors: T_k·f = e_0 and T_k·g = e_(k-1)
+        let inv = T::one() / self.col[0].clone();
+        let mut f = vec![inv.clone()];
+        let mut g = vec![inv.clone()];
+        let mut x = vec![b[0].clone() * inv];
+
+        for (k, bk) in b.iter().enumerate().skip(1) {
+            // errors of the vectors padded with a zero, in the new last and first row
+            let ef = (0..k).fold(T::zero(), |acc, j| acc + self.col[k - j].clone() * f[j].clone());
+            let eg = (0..k).fold(T::zero(), |acc, j| acc + self.row[j + 1].clone() * g[j].clone());
+
+            let denom = T::one() - ef.clone() * eg.clone();
+            if denom.is_zero() {
+                return None;
+            }
+            let scale = T::one() / denom;
+
+            let mut f_next = Vec::with_capacity(k + 1);
+            let mut g_next = Vec::with_capacity(k + 1);
+            for j in 0..=k {
+                let fj = if j < k { f[j].clone() } else { T::zero() };
+                let gj = if j > 0 { g[j - 1].clone() } else { T::zero() };
+                f_next.push((fj.clone() - ef.clone() * gj.clone()) * scale.clone());
+                g_next.push((gj - eg.clone() * fj) * scale.clone());
+            }
+            f = f_next;
+            g = g_next;
+
+            let ex = (0..k).fold(T::zero(), |acc, j| acc + self.col[k - j].clone() * x[j].clone());
+            let correction = bk.clone() - ex;
+            x.push(T::zero());
+            for (xj, gj) in x.iter_mut().zip(&g) {
+                *xj = xj.clone() + correction.clone() * gj.clone();
+            }
+        }
+
+        Some(Vector::from_data(x))
+    }
+}
+
+impl<T: Scalar> From<ToeplitzMatrix<T>> for Matrix<T> {
+    fn from(t: ToeplitzMatrix<T>) -> Self {
+        let n = t.size();
+        let mut m = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                m[i][j] = t.get(i, j);
+            }
+        }
+        m
+    }
+}
@@ -0,0 +1,230 @@
+use super::{Matrix, Scalar, Vector};
+
+/// # Upper triangular matrix
+/// A square matrix that is zero below the main diagonal.
+/// Only the `n·(n+1)/2` entries on and above the diagonal are stored, row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T = f64> {
+    n: usize,
+    data: Vec<T>,
+}
+
+/// # Lower triangular matrix
+/// A square matrix that is zero above the main diagonal.
+/// Only the `n·(n+1)/2` entries on and below the diagonal are stored, row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T = f64> {
+    n: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> UpperTriangular<T> {
+    ///# Description:
+    /// Take the upper triangle of a square matrix.
+    /// Returns `None` if the matrix is not square or has non-zero entries below the diagonal.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<UpperTriangular<T>> {
+        if m.width != m.height {
+            return None;
+        }
+        let mut data = Vec::with_capacity(m.height * (m.height + 1) / 2);
+        for i in 0..m.height {
+            if m[i][..i].iter().any(|x| !x.is_zero()) {
+                return None;
+            }
+            data.extend_from_slice(&m[i][i..]);
+        }
+        Some(UpperTriangular { n: m.height, data })
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    fn offset(&self, i: usize) -> usize {
+        // rows before i hold n, n-1, …, n-i+1 entries
+        i * self.n - i * i.saturating_sub(1) / 2
+    }
+
+    /// The entry in row `i` and column `j`, zero below the diagonal.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= self.n || j >= self.n {
+            panic!("Row or column index out of bounds");
+        }
+        if j < i {
+            return T::zero();
+        }
+        self.data[self.offset(i) + j - i].clone()
+    }
+
+    fn row(&self, i: usize) -> &[T] {
+        let start = self.offset(i);
+        &self.data[start..start + self.n - i]
+    }
+
+    /// The product of the diagonal.
+    pub fn det(&self) -> T {
+        (0..self.n).fold(T::one(), |acc, i| acc * self.row(i)[0].clone())
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<T> {
+        LowerTriangular::from_matrix(&Matrix::from(self.clone()).transpose())
+            .expect("transpose of an upper triangular matrix is lower triangular")
+    }
+
+    ///# Description:
+    /// The product `U·x` in O(n²).
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        (0..self.n)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(&x[i..])
+                    .fold(T::zero(), |acc, (a, x)| acc + a.clone() * x.clone())
+            })
+            .collect()
+    }
+
+    ///# Description:
+    /// Solve `U·x = b` by back substitution in O(n²).
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match or the diagonal contains a zero.
+    pub fn back_substitute(&self, b: &[T]) -> Vector<T> {
+        if b.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut x: Vector<T> = Vector::from(b);
+        for i in (0..self.n).rev() {
+            let row = self.row(i);
+            if row[0].is_zero() {
+                panic!("Matrix is singular");
+            }
+            let mut xi = x[i].clone();
+            for (a, xj) in row[1..].iter().zip(&x[i + 1..]) {
+                xi = xi - a.clone() * xj.clone();
+            }
+            x[i] = xi / row[0].clone();
+        }
+        x
+    }
+}
+
+impl<T: Scalar> LowerTriangular<T> {
+    ///# Description:
+    /// Take the lower triangle of a square matrix.
+    /// Returns `None` if the matrix is not square or has non-zero entries above the diagonal.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<LowerTriangular<T>> {
+        if m.width != m.height {
+            return None;
+        }
+        let mut data = Vec::with_capacity(m.height * (m.height + 1) / 2);
+        for i in 0..m.height {
+            if m[i][i + 1..].iter().any(|x| !x.is_zero()) {
+                return None;
+            }
+            data.extend_from_slice(&m[i][..=i]);
+        }
+        Some(LowerTriangular { n: m.height, data })
+    }
+
+    /// The number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    fn row(&self, i: usize) -> &[T] {
+        let start = i * (i + 1) / 2;
+        &self.data[start..=start + i]
+    }
+
+    /// The entry in row `i` and column `j`, zero above the diagonal.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= self.n || j >= self.n {
+            panic!("Row or column index out of bounds");
+        }
+        if j > i {
+            return T::zero();
+        }
+        self.row(i)[j].clone()
+    }
+
+    /// The product of the diagonal.
+    pub fn det(&self) -> T {
+        (0..self.n).fold(T::one(), |acc, i| acc * self.row(i)[i].clone())
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<T> {
+        UpperTriangular::from_matrix(&Matrix::from(self.clone()).transpose())
+            .expect("transpose of a lower triangular matrix is upper triangular")
+    }
+
+    ///# Description:
+    /// The product `L·x` in O(n²).
+    ///
+    /// # Panics:
+    /// Panics if the length of `x` does not match.
+    pub fn mul_vec(&self, x: &[T]) -> Vector<T> {
+        if x.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        (0..self.n)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(x)
+                    .fold(T::zero(), |acc, (a, x)| acc + a.clone() * x.clone())
+            })
+            .collect()
+    }
+
+    ///# Description:
+    /// Solve `L·x = b` by forward substitution in O(n²).
+    ///
+    /// # Panics:
+    /// Panics if the length of `b` does not match or the diagonal contains a zero.
+    pub fn forward_substitute(&self, b: &[T]) -> Vector<T> {
+        if b.len() != self.n {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut x: Vector<T> = Vector::from(b);
+        for i in 0..self.n {
+            let row = self.row(i);
+            if row[i].is_zero() {
+                panic!("Matrix is singular");
+            }
+            let mut xi = x[i].clone();
+            for (a, xj) in row[..i].iter().zip(&x[..i]) {
+                xi = xi - a.clone() * xj.clone();
+            }
+            x[i] = xi / row[i].clone();
+        }
+        x
+    }
+}
+
+impl<T: Scalar> From<UpperTriangular<T>> for Matrix<T> {
+    fn from(u: UpperTriangular<T>) -> Self {
+        let mut m = Matrix::new(u.n, u.n);
+        for i in 0..u.n {
+            m[i][i..].clone_from_slice(u.row(i));
+        }
+        m
+    }
+}
+
+impl<T: Scalar> From<LowerTriangular<T>> for Matrix<T> {
+    fn from(l: LowerTriangular<T>) -> Self {
+        let mut m = Matrix::new(l.n, l.n);
+        for i in 0..l.n {
+            m[i][..=i].clone_from_slice(l.row(i));
+        }
+        m
+    }
+}
@@ -0,0 +1,292 @@
+use super::{Matrix, Scalar};
+use std::fmt;
+use std::ops::{Add, Deref, DerefMut, Mul, Neg, Sub};
+
+/// # Vector
+/// A column vector of scalars. The element type defaults to `f64`.
+///
+/// The vector dereferences to a slice, so indexing, `len` and `iter`
+/// work the same as on a `Vec<T>`.
+#[derive(Clone, PartialEq, Default)]
+pub struct Vector<T = f64> {
+    data: Vec<T>,
+}
+
+impl<T> Vector<T> {
+    ///# Description:
+    /// Create a new vector from the given values.
+    pub fn from_data(data: Vec<T>) -> Vector<T> {
+        Vector { data }
+    }
+
+    ///# Description:
+    /// Consume the vector and return the underlying values.
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T: Scalar> Vector<T> {
+    ///# Description:
+    /// Create a new vector of the given length, initialized to zero.
+    pub fn new(len: usize) -> Vector<T> {
+        Vector {
+            data: vec![T::zero(); len],
+        }
+    }
+
+    ///# Description:
+    /// The inner product `Σ conj(a_i)·b_i`.
+    /// For real vectors this is the ordinary dot product.
+    ///
+    /// # Panics:
+    /// Panics if the vectors differ in length.
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        check_len(self, other);
+        self.iter()
+            .zip(other.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + a.conj() * b.clone())
+    }
+
+    ///# Description:
+    /// The cross product `a × b` of two vectors in three dimensions.
+    ///
+    /// # Panics:
+    /// Panics if either vector does not have exactly three entries.
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        if self.len() != 3 || other.len() != 3 {
+            panic!("Cross product is only defined for vectors of length 3");
+        }
+        let (a, b) = (&self.data, &other.data);
+        let c = |i: usize, j: usize| a[i].clone() * b[j].clone() - a[j].clone() * b[i].clone();
+        Vector::from_data(vec![c(1, 2), c(2, 0), c(0, 1)])
+    }
+
+    ///# Description:
+    /// The p-norm `(Σ |v_i|^p)^(1/p)`.
+    /// `p = f64::INFINITY` gives the maximum norm.
+    ///
+    /// # Panics:
+    /// Panics if `p < 1`, which is not a norm.
+    pub fn norm_p(&self, p: f64) -> f64 {
+        if p.is_nan() || p < 1.0 {
+            panic!("p-norm requires p >= 1");
+        }
+        if p.is_infinite() {
+            return self.norm_inf();
+        }
+        if p == 1.0 {
+            return self.norm_1();
+        }
+        self.iter()
+            .map(|x| x.abs().powf(p))
+            .sum::<f64>()
+            .powf(1.0 / p)
+    }
+
+    /// The sum of the absolute values.
+    pub fn norm_1(&self) -> f64 {
+        self.iter().map(|x| x.abs()).sum()
+    }
+
+    /// The euclidean length.
+    pub fn norm(&self) -> f64 {
+        self.iter().fold(0.0, |acc, x| acc.hypot(x.abs()))
+    }
+
+    /// The largest absolute value.
+    pub fn norm_inf(&self) -> f64 {
+        self.iter().map(|x| x.abs()).fold(0.0, f64::max)
+    }
+
+    ///# Description:
+    /// A vector pointing in the same direction with euclidean length 1.
+    /// Returns `None` for the zero vector.
+    ///
+    /// The length is a floating point number, so this is only meaningful for real and complex scalars.
+    /// For rationals the result is rounded, for finite fields like `Gf<P>` it has no meaning.
+    pub fn normalize(&self) -> Option<Vector<T>> {
+        let n = self.norm();
+        if n == 0.0 {
+            return None;
+        }
+        let inv = T::from_f64(1.0 / n);
+        Some(self.clone() * inv)
+    }
+
+    ///# Description:
+    /// The outer product `a·bᵀ`, a matrix of height `a.len()` and width `b.len()`.
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        let mut m = Matrix::new(self.len(), other.len());
+        for i in 0..self.len() {
+            for j in 0..other.len() {
+                m[i][j] = self[i].clone() * other[j].clone();
+            }
+        }
+        m
+    }
+
+    ///# Description:
+    /// Add `alpha·x` to the vector in place (`y ← alpha·x + y`).
+    ///
+    /// # Panics:
+    /// Panics if the vectors differ in length.
+    pub fn axpy(&mut self, alpha: T, x: &Vector<T>) {
+        check_len(self, x);
+        T::axpy(&mut self.data, &alpha, x);
+    }
+}
+
+fn check_len<T>(a: &Vector<T>, b: &Vector<T>) {
+    if a.len() != b.len() {
+        panic!("Vector lengths do not match");
+    }
+}
+
+impl<T> Deref for Vector<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> DerefMut for Vector<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Vector { data }
+    }
+}
+
+impl<T: Clone> From<&[T]> for Vector<T> {
+    fn from(data: &[T]) -> Self {
+        Vector {
+            data: data.to_vec(),
+        }
+    }
+}
+
+impl<T> From<Vector<T>> for Vec<T> {
+    fn from(v: Vector<T>) -> Self {
+        v.data
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for Vector<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        &self.data == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for Vector<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.data == other
+    }
+}
+
+impl<T: Scalar> Add for Vector<T> {
+    type Output = Vector<T>;
+    fn add(mut self, rhs: Vector<T>) -> Self::Output {
+        self.axpy(T::one(), &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Sub for Vector<T> {
+    type Output = Vector<T>;
+    fn sub(mut self, rhs: Vector<T>) -> Self::Output {
+        self.axpy(-T::one(), &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+    fn mul(mut self, rhs: T) -> Self::Output {
+        for x in self.data.iter_mut() {
+            *x = x.clone() * rhs.clone();
+        }
+        self
+    }
+}
+
+impl<T: Scalar> Neg for Vector<T> {
+    type Output = Vector<T>;
+    fn neg(self) -> Self::Output {
+        self.into_iter().map(|x| -x).collect()
+    }
+}
+
+/// Matrix-vector product `A·x`.
+impl<T: Scalar> Mul<&Vector<T>> for &Matrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, x: &Vector<T>) -> Self::Output {
+        if self.width != x.len() {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut res: Vector<T> = Vector::new(self.height);
+        for i in 0..self.height {
+            for (a, b) in self[i].iter().zip(x.iter()) {
+                res[i] = res[i].clone() + a.clone() * b.clone();
+            }
+        }
+        res
+    }
+}
+
+impl<T: Scalar> Mul<&Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+    fn mul(self, x: &Vector<T>) -> Self::Output {
+        &self * x
+    }
+}
+
+/// Vector-matrix product `xᵀ·A`, treating the vector as a row.
+impl<T: Scalar> Mul<&Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+    fn mul(self, m: &Matrix<T>) -> Self::Output {
+        if self.len() != m.height {
+            panic!("Matrix and vector lengths do not match");
+        }
+        let mut res: Vector<T> = Vector::new(m.width);
+        for (i, x) in self.iter().enumerate() {
+            for j in 0..m.width {
+                res[j] = res[j].clone() + x.clone() * m[i][j].clone();
+            }
+        }
+        res
+    }
+}
+
+impl<T: Scalar> Mul<Matrix<T>> for &Vector<T> {
+    type Output = Vector<T>;
+    fn mul(self, m: Matrix<T>) -> Self::Output {
+        self * &m
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.data, f)
+    }
+}
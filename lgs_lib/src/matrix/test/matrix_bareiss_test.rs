@@ -0,0 +1,92 @@
+use crate::matrix::matrix::{BigInt, Matrix};
+
+fn create_4x4_matrix() -> Matrix<i64> {
+    Matrix::from_data(vec![
+        vec![1, 2, 52, 2],
+        vec![1, 0, 1, 2],
+        vec![3, 2, 12, 2],
+        vec![3, 5, 3, 2],
+    ])
+}
+
+#[test]
+fn bareiss_det_of_1x1_matrix() {
+    let m: Matrix<i64> = Matrix::from_data(vec![vec![7]]);
+    assert_eq!(m.bareiss_det(), Some(7));
+}
+
+#[test]
+fn bareiss_det_of_2x2_matrix() {
+    let m: Matrix<i64> = Matrix::from_data(vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(m.bareiss_det(), Some(-2));
+}
+
+#[test]
+fn bareiss_det_of_4x4_matrix() {
+    assert_eq!(create_4x4_matrix().bareiss_det(), Some(684));
+}
+
+#[test]
+fn bareiss_det_with_row_swap() {
+    let m: Matrix<i64> = Matrix::from_data(vec![vec![0, 1], vec![1, 0]]);
+    assert_eq!(m.bareiss_det(), Some(-1));
+}
+
+#[test]
+fn bareiss_det_of_singular_matrix() {
+    let m: Matrix<i64> = Matrix::from_data(vec![vec![1, 1, 1], vec![2, 2, 2], vec![3, 3, 3]]);
+    assert_eq!(m.bareiss_det(), Some(0));
+    assert_eq!(m.bareiss_rank(), Some(1));
+}
+
+#[test]
+fn bareiss_rank_of_non_square_matrix() {
+    let m: Matrix<i64> = Matrix::from_data(vec![
+        vec![0, 2, 4, 1],
+        vec![0, 1, 2, 3],
+        vec![0, 3, 6, 4],
+    ]);
+    assert_eq!(m.bareiss_rank(), Some(2));
+
+    let (echelon, rank) = m.bareiss().unwrap();
+    assert_eq!(rank, 2);
+    assert_eq!(echelon, Matrix::from_data(vec![
+        vec![0, 2, 4, 1],
+        vec![0, 0, 0, 5],
+        vec![0, 0, 0, 0],
+    ]));
+}
+
+#[test]
+fn bareiss_det_reports_overflow() {
+    let big = i64::MAX / 2;
+    let m: Matrix<i64> = Matrix::from_data(vec![vec![big, 1], vec![1, big]]);
+    assert_eq!(m.bareiss_det(), None);
+}
+
+#[test]
+fn bareiss_det_of_big_integers() {
+    let big = i64::MAX / 2;
+    let m: Matrix<BigInt> = Matrix::from_data(vec![
+        vec![BigInt::from(big), BigInt::from(1)],
+        vec![BigInt::from(1), BigInt::from(big)],
+    ]);
+    let expected = &BigInt::from(big) * &BigInt::from(big) - BigInt::from(1);
+    assert_eq!(m.bareiss_det(), Some(expected));
+}
+
+#[test]
+fn bareiss_det_is_exact_where_cofactor_expansion_rounds() {
+    // Every entry is exactly representable as f64, but the products are not.
+    let n = 1_i64 << 30;
+    let m: Matrix<i64> = Matrix::from_data(vec![
+        vec![n + 1, n],
+        vec![n, n - 1],
+    ]);
+    assert_eq!(m.bareiss_det(), Some(-1));
+    let f = Matrix::from_data(vec![
+        vec![(n + 1) as f64, n as f64],
+        vec![n as f64, (n - 1) as f64],
+    ]);
+    assert_ne!(f.det(), -1.0);
+}
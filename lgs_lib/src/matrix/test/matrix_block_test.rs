@@ -0,0 +1,74 @@
+use crate::lgs::lgs;
+use crate::matrix::matrix::*;
+
+fn create_matrix() -> Matrix {
+    Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]])
+}
+
+#[test]
+fn test_hstack_and_vstack() {
+    let a: Matrix = Matrix::from_data(vec![vec![1.0], vec![2.0]]);
+    let b: Matrix = Matrix::from_data(vec![vec![3.0, 4.0], vec![5.0, 6.0]]);
+    assert_eq!(a.hstack(&b), Matrix::from_data(vec![vec![1.0, 3.0, 4.0], vec![2.0, 5.0, 6.0]]));
+
+    let c: Matrix = Matrix::from_data(vec![vec![7.0, 8.0]]);
+    assert_eq!(b.vstack(&c), Matrix::from_data(vec![vec![3.0, 4.0], vec![5.0, 6.0], vec![7.0, 8.0]]));
+}
+
+#[test]
+#[should_panic(expected = "Blocks in a row must have the same height")]
+fn test_hstack_height_mismatch_panics() {
+    let a: Matrix = Matrix::new(2, 1);
+    a.hstack(&Matrix::new(3, 1));
+}
+
+#[test]
+#[should_panic(expected = "Block rows must have the same width")]
+fn test_vstack_width_mismatch_panics() {
+    let a: Matrix = Matrix::new(2, 1);
+    a.vstack(&Matrix::new(2, 2));
+}
+
+#[test]
+fn test_block_assembles_kkt_system() {
+    // minimize ½xᵀHx subject to Ax = b, solved through [[H, Aᵀ], [A, 0]]·[x, λ] = [0, b]
+    let h: Matrix = Matrix::from_data(vec![vec![2.0, 0.0], vec![0.0, 2.0]]);
+    let a: Matrix = Matrix::from_data(vec![vec![1.0, 1.0]]);
+    let kkt = Matrix::block(&[&[&h, &a.transpose()], &[&a, &Matrix::new(1, 1)]]);
+    assert_eq!((kkt.height, kkt.width), (3, 3));
+    assert_eq!(kkt.slice(..2, ..2), h);
+    assert_eq!(kkt.slice(2.., ..2), a);
+
+    let x = lgs::solve_lu(kkt, vec![0.0, 0.0, 1.0]);
+    assert_eq!(x[..2], [0.5, 0.5]);
+}
+
+#[test]
+fn test_slice_ranges() {
+    let m = create_matrix();
+    assert_eq!(m.slice(1..3, 0..2), Matrix::from_data(vec![vec![4.0, 5.0], vec![7.0, 8.0]]));
+    assert_eq!(m.slice(..=0, 2..), Matrix::from_data(vec![vec![3.0]]));
+    assert_eq!(m.slice(.., ..), m);
+
+    let empty = m.slice(1..1, ..);
+    assert_eq!((empty.height, empty.width), (0, 3));
+}
+
+#[test]
+#[should_panic(expected = "Row or column index out of bounds")]
+fn test_slice_out_of_bounds_panics() {
+    create_matrix().slice(0..4, ..);
+}
+
+#[test]
+fn test_set_block() {
+    let mut m = create_matrix();
+    m.set_block(1, 1, &Matrix::from_data(vec![vec![0.0, 0.0], vec![0.0, 0.0]]));
+    assert_eq!(m, Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 0.0, 0.0], vec![7.0, 0.0, 0.0]]));
+}
+
+#[test]
+#[should_panic(expected = "Block does not fit into the matrix")]
+fn test_set_block_too_large_panics() {
+    create_matrix().set_block(2, 0, &Matrix::new(2, 1));
+}
@@ -17,14 +17,14 @@ fn test_create_matrix_from_number_str() {
 
 #[test]
 fn test_init_empty_matrix() {
-    let m = Matrix::new(0, 0);
+    let m: Matrix = Matrix::new(0, 0);
     assert_eq!(m.height, 0);
     assert_eq!(m.width, 0);
 }
 
 #[test]
 fn test_init_empty_1x1_matrix() {
-    let m = Matrix::new(1, 1);
+    let m: Matrix = Matrix::new(1, 1);
     assert_eq!(m.height, 1);
     assert_eq!(m.width, 1);
 }
@@ -1,4 +1,5 @@
-use crate::matrix::matrix::Matrix;
+use crate::matrix::matrix::{BigInt, Matrix, Rational};
+use crate::test_util::rationals;
 
 fn create_4x4_matrix() -> Matrix {
     Matrix::from_data(vec![
@@ -40,3 +41,13 @@ fn calc_determinant_of_3x3_matrix() {
         ]);
     assert_eq!(m.det(), 0.0);
 }
+
+#[test]
+fn calc_determinant_of_rational_matrix_exactly() {
+    let data: Vec<Vec<i64>> = (0..14)
+        .map(|i| (0..14).map(|j| ((i * 5 + j * 3) % 7) as i64 - (i == j) as i64 * 9).collect())
+        .collect();
+    let integers = data.iter().map(|r| r.iter().map(|&x| BigInt::from(x)).collect()).collect();
+    let exact = Matrix::from_data(integers).bareiss_det().unwrap();
+    assert_eq!(rationals(data).det(), Rational::from(exact));
+}
@@ -0,0 +1,92 @@
+use crate::matrix::matrix::{Complex, Matrix, Rational};
+use crate::test_util::assert_matrix_close;
+
+/// Deterministic pseudo random entries, so mismatches are reproducible.
+fn pseudo_random(height: usize, width: usize, seed: u64) -> Matrix {
+    let mut state = seed;
+    let data = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+                })
+                .collect()
+        })
+        .collect();
+    Matrix::from_data(data)
+}
+
+#[test]
+fn test_blocked_matches_naive_across_block_edges() {
+    // sizes around the block size of 64 exercise partial tiles in every dimension
+    for &(n, k, m) in &[(1, 1, 1), (3, 70, 5), (65, 64, 63), (130, 67, 129)] {
+        let a = pseudo_random(n, k, 1);
+        let b = pseudo_random(k, m, 2);
+        assert_eq!(a.mul_blocked(&b), a.mul_naive(&b));
+    }
+}
+
+#[test]
+fn test_blocked_matches_naive_for_f32() {
+    let a = Matrix::from_data((0..9).map(|i| (0..11).map(|j| (i * j) as f32 / 7.0).collect()).collect());
+    let b = Matrix::from_data((0..11).map(|i| (0..6).map(|j| (i + j) as f32 / 3.0).collect()).collect());
+    assert_eq!(a.mul_blocked(&b), a.mul_naive(&b));
+}
+
+#[test]
+fn test_blocked_generic_scalars() {
+    let a = Matrix::from_data(vec![vec![Rational::from(1), Rational::from(2)]]);
+    let b = Matrix::from_data(vec![vec![Rational::from(3)], vec![Rational::from(4)]]);
+    assert_eq!(&a * &b, Matrix::from_data(vec![vec![Rational::from(11)]]));
+
+    let i = Matrix::from_data(vec![vec![Complex::i()]]);
+    assert_eq!(&i * &i, Matrix::from_data(vec![vec![Complex::new(-1.0, 0.0)]]));
+}
+
+#[test]
+#[should_panic(expected = "Matrix dimensions do not match")]
+fn test_blocked_dimension_mismatch_panics() {
+    let a: Matrix = Matrix::new(2, 3);
+    a.mul_blocked(&Matrix::new(2, 3));
+}
+
+#[test]
+fn test_strassen_matches_classical_for_odd_and_non_square_sizes() {
+    for &(n, k, m) in &[(16, 16, 16), (37, 37, 37), (13, 29, 7), (1, 40, 1), (50, 3, 21)] {
+        let a = pseudo_random(n, k, 3);
+        let b = pseudo_random(k, m, 4);
+        assert_matrix_close(&a.mul_strassen_with_threshold(&b, 4), &(&a * &b), 1e-10);
+    }
+}
+
+#[test]
+fn test_strassen_tiles_strongly_rectangular_sizes() {
+    for &(n, k, m) in &[(40, 9, 23), (9, 40, 30), (30, 8, 5), (6, 6, 50)] {
+        let a = pseudo_random(n, k, 7);
+        let b = pseudo_random(k, m, 8);
+        assert_matrix_close(&a.mul_strassen_with_threshold(&b, 4), &(&a * &b), 1e-10);
+    }
+}
+
+#[test]
+fn test_strassen_of_thin_matrices_is_the_blocked_product() {
+    let u = pseudo_random(1000, 1, 9);
+    let v = pseudo_random(1, 1000, 10);
+    assert_eq!(u.mul_strassen_with_threshold(&v, 4), u.mul_blocked(&v));
+}
+
+#[test]
+fn test_strassen_below_threshold_is_the_blocked_product() {
+    let a = pseudo_random(20, 20, 5);
+    let b = pseudo_random(20, 20, 6);
+    assert_eq!(a.mul_strassen(&b), a.mul_blocked(&b));
+}
+
+#[test]
+fn test_strassen_is_exact_for_rationals() {
+    let a = Matrix::from_data(
+        (0..9).map(|i| (0..9).map(|j| Rational::new((i * j + 1).into(), (i + 2).into())).collect()).collect(),
+    );
+    assert_eq!(a.mul_strassen_with_threshold(&a, 2), a.mul_naive(&a));
+}
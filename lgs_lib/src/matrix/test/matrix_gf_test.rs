@@ -0,0 +1,150 @@
+use crate::lgs::lgs::{inverse, solve};
+use crate::matrix::matrix::{Gf, Gf2Matrix, Matrix, Rational, Scalar};
+
+type F7 = Gf<7>;
+
+fn gf7(rows: Vec<Vec<i64>>) -> Matrix<F7> {
+    Matrix::from_data(rows.into_iter().map(|r| r.into_iter().map(F7::new).collect()).collect())
+}
+
+fn bits(rows: &[&str]) -> Gf2Matrix {
+    Gf2Matrix::from_data(rows.iter().map(|r| r.bytes().map(|b| b == b'1').collect()).collect())
+}
+
+#[test]
+fn test_gf_arithmetic() {
+    assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+    assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+    assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+    assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+    assert_eq!(F7::new(-1).value(), 6);
+    assert_eq!(F7::new(3).pow(6), F7::one());
+}
+
+#[test]
+fn test_solve_over_gf7() {
+    let m = gf7(vec![vec![1, 2], vec![3, 4]]);
+    let (_, x) = solve(m.clone(), vec![F7::new(5), F7::new(6)]);
+    assert_eq!(x, vec![F7::new(3), F7::new(1)]);
+}
+
+#[test]
+fn test_inverse_over_gf7() {
+    let m = gf7(vec![vec![1, 2], vec![3, 4]]);
+    let inv = inverse(m.clone());
+    assert_eq!(m * inv, gf7(vec![vec![1, 0], vec![0, 1]]));
+}
+
+#[test]
+fn test_det_over_gf7() {
+    let m = gf7(vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(m.det(), F7::new(-2));
+}
+
+#[test]
+fn test_rref_rank_and_null_space_over_gf7() {
+    let m = gf7(vec![vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+    let (r, pivots) = m.rref();
+    assert_eq!(pivots, vec![0, 1]);
+    assert_eq!(r, gf7(vec![vec![1, 0, 1], vec![0, 1, 1], vec![0, 0, 0]]));
+    assert_eq!(m.rank(), 2);
+
+    let null = m.null_space();
+    assert_eq!(null, vec![vec![F7::new(-1), F7::new(-1), F7::one()]]);
+    let x = Matrix::from_data(null.into_iter().map(|v| vec![v[0], v[1], v[2]]).collect()).transpose();
+    assert_eq!(m * x, gf7(vec![vec![0], vec![0], vec![0]]));
+}
+
+#[test]
+fn test_rref_of_rational_matrix() {
+    let m: Matrix<Rational> = Matrix::from_str("[[2, 4, 2],[1, 3, 2]]");
+    let (r, pivots) = m.rref();
+    assert_eq!(pivots, vec![0, 1]);
+    assert_eq!(r, Matrix::from_str("[[1, 0, -1],[0, 1, 1]]"));
+}
+
+#[test]
+fn test_rref_of_rational_matrix_beyond_f64_range() {
+    let big: Rational = "1e400".parse().unwrap();
+    let m = Matrix::from_data(vec![vec![big.clone(), big.clone()], vec![big.clone(), big]]);
+    let (r, pivots) = m.rref();
+    assert_eq!(pivots, vec![0]);
+    assert_eq!(r, Matrix::from_str("[[1, 1],[0, 0]]"));
+
+    let tiny: Rational = "1e-400".parse().unwrap();
+    let m = Matrix::from_data(vec![vec![Rational::zero(), Rational::one()], vec![tiny, Rational::zero()]]);
+    assert_eq!(m.rank(), 2);
+}
+
+#[test]
+fn test_null_space_of_float_matrix() {
+    let m = Matrix::from_data(vec![vec![1.0, 1.0], vec![2.0, 2.0]]);
+    assert_eq!(m.rank(), 1);
+    assert_eq!(m.null_space(), vec![vec![-1.0, 1.0]]);
+}
+
+#[test]
+fn test_float_rank_does_not_depend_on_scale() {
+    for scale in [1e-20, 1.0, 1e20] {
+        let id = Matrix::from_data(vec![vec![scale, 0.0], vec![0.0, scale]]);
+        assert_eq!(id.rank(), 2);
+        let m = Matrix::from_data(vec![vec![scale, 2.0 * scale], vec![2.0 * scale, 4.0 * scale]]);
+        assert_eq!(m.rank(), 1);
+        assert_eq!(m.null_space().len(), 1);
+    }
+    let nearly_dependent = Matrix::from_data(vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-17]]);
+    assert_eq!(nearly_dependent.rank(), 1);
+}
+
+#[test]
+fn test_gf2_rank_and_rref() {
+    let m = bits(&["110", "011", "101"]);
+    assert_eq!(m.rank(), 2);
+    let (r, pivots) = m.rref();
+    assert_eq!(pivots, vec![0, 1]);
+    assert_eq!(r, bits(&["101", "011", "000"]));
+}
+
+#[test]
+fn test_gf2_solve() {
+    let m = bits(&["110", "011", "111"]);
+    let b = vec![true, false, false];
+    let x = m.solve(&b).unwrap();
+    assert_eq!(m.mul_vec(&x), b);
+}
+
+#[test]
+fn test_gf2_solve_inconsistent_system() {
+    let m = bits(&["11", "11"]);
+    assert_eq!(m.solve(&[true, false]), None);
+}
+
+#[test]
+fn test_gf2_inverse() {
+    let m = bits(&["110", "011", "111"]);
+    let inv = m.inverse().unwrap();
+    assert_eq!(inv, bits(&["011", "111", "101"]));
+    assert_eq!(bits(&["11", "11"]).inverse(), None);
+    assert_eq!(Gf2Matrix::new(0, 0).inverse(), Some(Gf2Matrix::new(0, 0)));
+}
+
+#[test]
+fn test_gf2_null_space_of_hamming_parity_check() {
+    let h = bits(&["1010101", "0110011", "0001111"]);
+    let code = h.null_space();
+    assert_eq!(code.len(), 4);
+    for c in code {
+        assert_eq!(h.mul_vec(&c), vec![false; 3]);
+    }
+}
+
+#[test]
+fn test_gf2_wide_rows_span_several_words() {
+    let mut m = Gf2Matrix::identity(130);
+    m.set(0, 129, true);
+    m.xor_rows(0, 129);
+    assert!(!m.get(129, 129));
+    assert!(m.get(129, 0));
+    assert_eq!(m.rank(), 130);
+    assert_eq!(m.inverse().unwrap().inverse().unwrap(), m);
+}
@@ -11,19 +11,19 @@ fn test_matrix_with_no_linear_dependencies() {
 
 #[test]
 fn test_row_is_linear_dependant_with_empty_vectors() {
-    assert!(!is_a_linear_dependent_row(&vec![], &vec![]));
+    assert!(!is_a_linear_dependent_row::<f64>(&[], &[]));
 }
 
 #[test]
 fn test_row_linear_is_linear_dependent_is_true() {
-    assert!(is_a_linear_dependent_row(&vec![1.0], &vec![2.0]));
-    assert!(is_a_linear_dependent_row(&vec![2.0], &vec![1.0]));
+    assert!(is_a_linear_dependent_row(&[1.0], &[2.0]));
+    assert!(is_a_linear_dependent_row(&[2.0], &[1.0]));
 }
 
 #[test]
 fn test_row_linear_dependent_row_with_3_values() {
-    assert!(!is_a_linear_dependent_row(&vec![1_f64, 2_f64, 3_f64], &vec![3_f64, 4_f64, 6_f64]));
-    assert!(!is_a_linear_dependent_row(&vec![1_f64, 2_f64, 3_f64], &vec![3_f64, 3_f64, 3_f64]));
+    assert!(!is_a_linear_dependent_row(&[1_f64, 2_f64, 3_f64], &[3_f64, 4_f64, 6_f64]));
+    assert!(!is_a_linear_dependent_row(&[1_f64, 2_f64, 3_f64], &[3_f64, 3_f64, 3_f64]));
 }
 
 
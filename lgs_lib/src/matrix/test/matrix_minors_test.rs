@@ -0,0 +1,114 @@
+use crate::matrix::matrix::*;
+use crate::test_util::{assert_matrix_close, rationals};
+
+#[test]
+fn test_trace() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0], vec![3.0, -4.5]]);
+    assert_eq!(m.trace(), -3.5);
+}
+
+#[test]
+#[should_panic(expected = "Matrix must be square")]
+fn test_trace_of_non_square_matrix_panics() {
+    Matrix::<f64>::new(2, 3).trace();
+}
+
+#[test]
+fn test_minor_and_cofactor() {
+    let m = rationals(vec![vec![1, 2, 3], vec![0, 4, 5], vec![1, 0, 6]]);
+    assert_eq!(m.minor(0, 0), Rational::from(24));
+    assert_eq!(m.minor(0, 1), Rational::from(-5));
+    assert_eq!(m.cofactor(0, 1), Rational::from(5));
+    assert_eq!(m.minor(2, 2), m.submatrix(2, 2).det());
+}
+
+#[test]
+#[should_panic(expected = "Row or column index out of bounds")]
+fn test_minor_out_of_bounds_panics() {
+    Matrix::<f64>::new(2, 2).minor(2, 0);
+}
+
+#[test]
+fn test_cofactor_matrix_and_adjugate() {
+    let m = rationals(vec![vec![1, 2, 3], vec![0, 4, 5], vec![1, 0, 6]]);
+    assert_eq!(
+        m.cofactor_matrix(),
+        rationals(vec![vec![24, 5, -4], vec![-12, 3, 2], vec![-2, -5, 4]])
+    );
+    // A·adj(A) = det(A)·I
+    let det = m.det();
+    assert_eq!(&m * &m.adjugate(), Matrix::identity(3) * det);
+}
+
+#[test]
+fn test_adjugate_matches_the_cofactors() {
+    let m = rationals(
+        (0..6)
+            .map(|i| (0..6).map(|j| ((i * 7 + j * 3) % 5) as i64 - (i == j) as i64 * 4).collect())
+            .collect(),
+    );
+    let adj = m.adjugate();
+    for i in 0..6 {
+        for j in 0..6 {
+            assert_eq!(adj[j][i], m.cofactor(i, j));
+        }
+    }
+
+    let f = Matrix::from_data(vec![vec![4.0, 1.0, 2.0], vec![0.5, 3.0, 1.0], vec![1.0, -1.0, 5.0]]);
+    let expected = Matrix::identity(3) * f.det();
+    assert_matrix_close(&(&f * &f.adjugate()), &expected, 1e-12);
+}
+
+#[test]
+fn test_adjugate_of_singular_matrix() {
+    let m = rationals(vec![vec![1, 2], vec![2, 4]]);
+    assert_eq!(m.adjugate(), rationals(vec![vec![4, -2], vec![-2, 1]]));
+    assert_eq!(&m * &m.adjugate(), Matrix::new(2, 2));
+}
+
+#[test]
+fn test_principal_minors() {
+    let m = rationals(vec![vec![2, 1, 0], vec![1, 3, 1], vec![0, 1, 4]]);
+    assert_eq!(m.principal_minors(1), vec![Rational::from(2), Rational::from(3), Rational::from(4)]);
+    // {0, 1}, {0, 2}, {1, 2}
+    assert_eq!(m.principal_minors(2), vec![Rational::from(5), Rational::from(8), Rational::from(11)]);
+    assert_eq!(m.principal_minors(3), vec![m.det()]);
+    assert_eq!(m.principal_minors(0), vec![Rational::from(1)]);
+
+    // the sums are the coefficients of the characteristic polynomial up to sign
+    let p = m.char_poly();
+    let e2 = m.principal_minors(2).into_iter().fold(Rational::from(0), |a, b| a + b);
+    assert_eq!(p[1], e2);
+}
+
+#[test]
+fn test_leading_principal_minors() {
+    let m = rationals(vec![vec![2, 1, 0], vec![1, 3, 1], vec![0, 1, 4]]);
+    assert_eq!(m.leading_principal_minors(), vec![Rational::from(2), Rational::from(5), Rational::from(18)]);
+}
+
+#[test]
+fn test_is_positive_definite() {
+    let spd = Matrix::from_data(vec![vec![2.0, -1.0, 0.0], vec![-1.0, 2.0, -1.0], vec![0.0, -1.0, 2.0]]);
+    assert!(spd.is_positive_definite());
+
+    // indefinite: the second leading minor is negative
+    let indefinite = Matrix::from_data(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+    assert!(!indefinite.is_positive_definite());
+
+    // semidefinite
+    assert!(!rationals(vec![vec![1, 1], vec![1, 1]]).is_positive_definite());
+
+    // positive minors but not symmetric
+    assert!(!Matrix::from_data(vec![vec![1.0, 5.0], vec![0.0, 1.0]]).is_positive_definite());
+}
+
+#[test]
+fn test_is_positive_definite_does_not_depend_on_scale() {
+    for scale in [1e-9, 1.0, 1e9] {
+        let id = Matrix::from_data(vec![vec![scale, 0.0, 0.0], vec![0.0, scale, 0.0], vec![0.0, 0.0, scale]]);
+        assert!(id.is_positive_definite());
+        let semidefinite = Matrix::from_data(vec![vec![scale, scale / 3.0], vec![scale / 3.0, scale / 9.0]]);
+        assert!(!semidefinite.is_positive_definite());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::matrix::matrix::{BigInt, Matrix};
+
+fn mul(a: &Matrix<i64>, b: &Matrix<i64>) -> Matrix<i64> {
+    Matrix::from_data(
+        (0..a.height)
+            .map(|i| (0..b.width).map(|j| (0..a.width).map(|k| a[i][k] * b[k][j]).sum()).collect())
+            .collect(),
+    )
+}
+
+fn is_unimodular(u: &Matrix<i64>) -> bool {
+    matches!(u.bareiss_det(), Some(1) | Some(-1))
+}
+
+#[test]
+fn hermite_normal_form_of_3x4_matrix() {
+    let a: Matrix<i64> = Matrix::from_data(vec![
+        vec![2, 3, 6, 2],
+        vec![5, 6, 1, 6],
+        vec![8, 3, 1, 1],
+    ]);
+    let (h, u) = a.hermite_normal_form().unwrap();
+    assert_eq!(h, Matrix::from_data(vec![
+        vec![1, 0, 50, -11],
+        vec![0, 3, 28, -2],
+        vec![0, 0, 61, -13],
+    ]));
+    assert_eq!(mul(&u, &a), h);
+    assert!(is_unimodular(&u));
+}
+
+#[test]
+fn hermite_normal_form_of_rank_deficient_matrix() {
+    let a: Matrix<i64> = Matrix::from_data(vec![vec![2, 4], vec![3, 6], vec![-1, -2]]);
+    let (h, u) = a.hermite_normal_form().unwrap();
+    assert_eq!(h, Matrix::from_data(vec![vec![1, 2], vec![0, 0], vec![0, 0]]));
+    assert_eq!(mul(&u, &a), h);
+    assert!(is_unimodular(&u));
+}
+
+#[test]
+fn smith_normal_form_of_3x3_matrix() {
+    let a: Matrix<i64> = Matrix::from_data(vec![
+        vec![2, 4, 4],
+        vec![-6, 6, 12],
+        vec![10, -4, -16],
+    ]);
+    let (s, u, v) = a.smith_normal_form().unwrap();
+    assert_eq!(s, Matrix::from_data(vec![vec![2, 0, 0], vec![0, 6, 0], vec![0, 0, 12]]));
+    assert_eq!(mul(&mul(&u, &a), &v), s);
+    assert!(is_unimodular(&u));
+    assert!(is_unimodular(&v));
+}
+
+#[test]
+fn smith_normal_form_enforces_divisibility() {
+    let a: Matrix<i64> = Matrix::from_data(vec![vec![2, 0], vec![0, 3]]);
+    let (s, u, v) = a.smith_normal_form().unwrap();
+    assert_eq!(s, Matrix::from_data(vec![vec![1, 0], vec![0, 6]]));
+    assert_eq!(mul(&mul(&u, &a), &v), s);
+}
+
+#[test]
+fn smith_normal_form_of_non_square_matrix() {
+    let a: Matrix<i64> = Matrix::from_data(vec![vec![4, 6, 8], vec![2, 2, 2]]);
+    let (s, u, v) = a.smith_normal_form().unwrap();
+    assert_eq!(s, Matrix::from_data(vec![vec![2, 0, 0], vec![0, 2, 0]]));
+    assert_eq!(mul(&mul(&u, &a), &v), s);
+    assert!(is_unimodular(&u));
+    assert!(is_unimodular(&v));
+}
+
+#[test]
+fn smith_normal_form_solves_diophantine_system() {
+    // 2x + 4y = 6, 6x - 2y = 4  has the integer solution x = 1, y = 1.
+    let a: Matrix<i64> = Matrix::from_data(vec![vec![2, 4], vec![6, -2]]);
+    let b = [6, 4];
+    let (s, u, v) = a.smith_normal_form().unwrap();
+    let c: Vec<i64> = (0..2).map(|i| u[i][0] * b[0] + u[i][1] * b[1]).collect();
+    let y: Vec<i64> = (0..2).map(|i| {
+        assert_eq!(c[i] % s[i][i], 0);
+        c[i] / s[i][i]
+    }).collect();
+    let x: Vec<i64> = (0..2).map(|i| v[i][0] * y[0] + v[i][1] * y[1]).collect();
+    assert_eq!(x, vec![1, 1]);
+}
+
+#[test]
+fn smith_normal_form_of_big_integers() {
+    let a: Matrix<BigInt> = Matrix::from_data(vec![
+        vec![BigInt::from(6), BigInt::from(4)],
+        vec![BigInt::from(4), BigInt::from(6)],
+    ]);
+    let (s, _, _) = a.smith_normal_form().unwrap();
+    assert_eq!(s, Matrix::from_data(vec![
+        vec![BigInt::from(2), BigInt::from(0)],
+        vec![BigInt::from(0), BigInt::from(10)],
+    ]));
+}
+
+#[test]
+fn normal_forms_report_overflow() {
+    let a: Matrix<i64> = Matrix::from_data(vec![vec![i64::MIN, 1], vec![1, i64::MAX]]);
+    assert_eq!(a.smith_normal_form(), None);
+}
@@ -52,3 +52,61 @@ fn test_matrix_transpose() {
 
 
 
+
+#[test]
+fn test_matrix_ops_on_references() {
+    let m1 = Matrix::from_data(vec![vec![1_f64, 2_f64], vec![3_f64, 4_f64]]);
+    let m2 = Matrix::from_data(vec![vec![5_f64, 6_f64], vec![7_f64, 8_f64]]);
+
+    assert_eq!(&m1 + &m2, m1.clone() + m2.clone());
+    assert_eq!(&m1 - &m2, m1.clone() - m2.clone());
+    assert_eq!(&m1 * &m2, m1.clone() * m2.clone());
+    assert_eq!(&m1 * 2.0, m1.clone() * 2.0);
+    assert_eq!(&m1 / 2.0, m1.clone() / 2.0);
+    assert_eq!(m1.clone() * &m2, &m1 * m2.clone());
+}
+
+#[test]
+fn test_matrix_assign_ops() {
+    let m1 = Matrix::from_data(vec![vec![1_f64, 2_f64], vec![3_f64, 4_f64]]);
+    let mut m = m1.clone();
+
+    m += &m1;
+    assert_eq!(m, &m1 * 2.0);
+    m -= m1.clone();
+    assert_eq!(m, m1);
+    m *= 4.0;
+    m /= 2.0;
+    assert_eq!(m, &m1 + &m1);
+    m *= &m1;
+    assert_eq!(m, Matrix::from_data(vec![vec![14_f64, 20_f64], vec![30_f64, 44_f64]]));
+}
+
+#[test]
+fn test_matrix_neg_and_scalar_on_left() {
+    let m1 = Matrix::from_data(vec![vec![1_f64, -2_f64]]);
+    assert_eq!(-&m1, Matrix::from_data(vec![vec![-1_f64, 2_f64]]));
+    assert_eq!(2.0 * &m1, &m1 * 2.0);
+    assert_eq!(-m1.clone(), -1.0 * m1);
+
+    let r = Matrix::from_data(vec![vec![Rational::from(1), Rational::from(2)]]);
+    assert_eq!(Rational::from(3) * &r, &r * Rational::from(3));
+
+    let g = Matrix::from_data(vec![vec![Gf::<5>::new(3)]]);
+    assert_eq!(Gf::<5>::new(2) * g, Matrix::from_data(vec![vec![Gf::<5>::new(1)]]));
+}
+
+#[test]
+#[should_panic(expected = "Matrix dimensions do not match")]
+fn test_matrix_add_dimension_mismatch_panics() {
+    let m1: Matrix = Matrix::new(2, 2);
+    let m2: Matrix = Matrix::new(2, 3);
+    let _ = m1 + m2;
+}
+
+#[test]
+#[should_panic(expected = "Matrix dimensions do not match")]
+fn test_matrix_sub_assign_dimension_mismatch_panics() {
+    let mut m1: Matrix = Matrix::new(3, 2);
+    m1 -= Matrix::new(2, 2);
+}
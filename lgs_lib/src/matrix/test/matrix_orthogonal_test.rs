@@ -0,0 +1,110 @@
+use crate::matrix::matrix::*;
+
+fn assert_orthonormal<T: Scalar>(basis: &[Vector<T>]) {
+    for (i, a) in basis.iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((a.dot(b) - T::from_f64(expected)).abs() < 1e-12, "{:?}", basis);
+        }
+    }
+}
+
+fn rank_two() -> Matrix {
+    // third column is the sum of the first two, last row is the difference of the first two
+    Matrix::from_data(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![0.0, 1.0, 1.0],
+        vec![1.0, 1.0, 2.0],
+    ])
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let m = Matrix::from_data(vec![vec![3.0, 1.0], vec![4.0, 1.0], vec![0.0, 1.0]]);
+    let q = m.gram_schmidt(1e-10);
+    assert_eq!((q.height, q.width), (3, 2));
+    assert!((q[0][0] - 0.6).abs() < 1e-15 && (q[1][0] - 0.8).abs() < 1e-15);
+    let qtq = &q.transpose() * &q;
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((qtq[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-14);
+        }
+    }
+}
+
+#[test]
+fn test_gram_schmidt_drops_dependent_columns() {
+    assert_eq!(rank_two().gram_schmidt(1e-10).width, 2);
+    assert_eq!(Matrix::<f64>::new(3, 2).gram_schmidt(1e-10).width, 0);
+}
+
+#[test]
+fn test_gram_schmidt_stays_orthogonal_for_ill_conditioned_columns() {
+    // nearly parallel columns, where classical Gram-Schmidt loses orthogonality
+    let e = 1e-9;
+    let m = Matrix::from_data(vec![vec![1.0, 1.0, 1.0], vec![e, 0.0, 0.0], vec![0.0, e, 0.0], vec![0.0, 0.0, e]]);
+    let q = m.gram_schmidt(1e-12);
+    assert_eq!(q.width, 3);
+    let columns: Vec<Vector> = (0..3).map(|j| (0..4).map(|i| q[i][j]).collect()).collect();
+    assert_orthonormal(&columns);
+}
+
+#[test]
+fn test_fundamental_subspaces() {
+    let m = rank_two();
+    let col = m.column_space(1e-10);
+    let row = m.row_space(1e-10);
+    let null = m.orthonormal_null_space(1e-10);
+    let left = m.left_null_space(1e-10);
+
+    assert_eq!((col.len(), row.len(), null.len(), left.len()), (2, 2, 1, 1));
+    assert_orthonormal(&col);
+    assert_orthonormal(&row);
+
+    let x = &null[0];
+    assert!((&m * x).norm() < 1e-12);
+    assert!((x[0] - x[1]).abs() < 1e-12 && (x[0] + x[2]).abs() < 1e-12);
+
+    let y = &left[0];
+    assert!((y * &m).norm() < 1e-12);
+    for r in &row {
+        assert!(r.dot(x).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_null_space_of_full_rank_and_zero_matrix() {
+    assert!(Matrix::<f64>::identity(3).orthonormal_null_space(1e-10).is_empty());
+    let null = Matrix::<f64>::new(2, 3).orthonormal_null_space(1e-10);
+    assert_eq!(null.len(), 3);
+    assert_orthonormal(&null);
+}
+
+#[test]
+fn test_tolerance_decides_rank() {
+    let m = Matrix::from_data(vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-8]]);
+    assert_eq!(m.column_space(1e-12).len(), 2);
+    assert_eq!(m.column_space(1e-6).len(), 1);
+    assert_eq!(m.orthonormal_null_space(1e-6).len(), 1);
+}
+
+#[test]
+fn test_complex_null_space() {
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    let m = Matrix::from_data(vec![vec![c(1.0, 0.0), c(0.0, 1.0)], vec![c(0.0, 1.0), c(-1.0, 0.0)]]);
+    let null = m.orthonormal_null_space(1e-10);
+    assert_eq!(null.len(), 1);
+    assert!((&m * &null[0]).norm() < 1e-12);
+    let left = m.left_null_space(1e-10);
+    assert_eq!(left.len(), 1);
+    assert_orthonormal(&left);
+    assert!((&left[0].iter().map(|x| x.conj()).collect::<Vector<Complex>>() * &m).norm() < 1e-12);
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    let vectors: Vec<Vector> = vec![vec![1.0, 1.0, 0.0].into(), vec![2.0, 2.0, 0.0].into(), vec![0.0, 1.0, 1.0].into()];
+    let basis = Matrix::orthonormal_basis(&vectors, 1e-10);
+    assert_eq!(basis.len(), 2);
+    assert_orthonormal(&basis);
+}
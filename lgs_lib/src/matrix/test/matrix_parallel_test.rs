@@ -0,0 +1,129 @@
+use crate::lgs::lgs::lu_decompose;
+use crate::matrix::matrix::parallel;
+use crate::matrix::matrix::{Matrix, Rational};
+
+// These matrices are above the parallel thresholds, so with `--features parallel`
+// the results of the thread pool are compared against straightforward serial loops.
+
+fn create_matrix(height: usize, width: usize) -> Matrix {
+    let data = (0..height)
+        .map(|i| (0..width).map(|j| ((i * 31 + j * 17) % 101) as f64 / 7.0 - 3.0).collect())
+        .collect();
+    Matrix::from_data(data)
+}
+
+#[test]
+fn test_parallel_elementwise_ops_match_serial_loops() {
+    let a = create_matrix(300, 200);
+    let b = create_matrix(300, 200).transpose().transpose() * 0.5;
+
+    let sum = &a + &b;
+    let scaled = &a / 3.0;
+    let neg = -&a;
+    for i in 0..a.height {
+        for j in 0..a.width {
+            assert_eq!(sum[i][j], a[i][j] + b[i][j]);
+            assert_eq!(scaled[i][j], a[i][j] / 3.0);
+            assert_eq!(neg[i][j], -a[i][j]);
+        }
+    }
+}
+
+#[test]
+fn test_parallel_transpose() {
+    let a = create_matrix(250, 190);
+    let t = a.transpose();
+    for i in 0..a.height {
+        for j in 0..a.width {
+            assert_eq!(t[j][i], a[i][j]);
+        }
+    }
+}
+
+#[test]
+fn test_parallel_product_matches_naive() {
+    let a = create_matrix(130, 70);
+    let b = create_matrix(70, 90);
+    assert_eq!(&a * &b, a.mul_naive(&b));
+}
+
+#[test]
+fn test_parallel_lu_is_deterministic() {
+    let a = create_matrix(200, 200) + Matrix::from_data(
+        (0..200).map(|i| (0..200).map(|j| if i == j { 500.0 } else { 0.0 }).collect()).collect(),
+    );
+    let lu = lu_decompose(a.clone());
+    assert_eq!(lu.u(), lu_decompose(a.clone()).u());
+
+    let product = &lu.l() * &lu.u();
+    for (i, &p) in lu.permutation().iter().enumerate() {
+        for j in 0..a.width {
+            assert!((product[i][j] - a[p][j]).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_parallel_det_matches_lu() {
+    let a = Matrix::from_data(
+        (0..8)
+            .map(|i| (0..8).map(|j| Rational::from(((i * 5 + j * 3) % 7) as i64 - (i == j) as i64 * 9)).collect())
+            .collect(),
+    );
+    let exact = lu_decompose(a.clone()).det();
+    assert_eq!(a.det(), exact);
+}
+
+#[test]
+fn test_parallel_det_of_product() {
+    // L·U with a unit lower L and an upper U whose diagonal multiplies to 1
+    let n = 200;
+    let l = Matrix::from_data(
+        (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else if j < i { ((i + j) % 5) as f64 / 100.0 } else { 0.0 }).collect())
+            .collect(),
+    );
+    let u = Matrix::from_data(
+        (0..n)
+            .map(|i| (0..n).map(|j| if i == j { [2.0, 0.5][i % 2] } else if j > i { ((i * j) % 3) as f64 / 100.0 } else { 0.0 }).collect())
+            .collect(),
+    );
+    assert!(((&l * &u).det() - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_parallel_solve_and_inverse() {
+    let n = 200;
+    let mut a = create_matrix(n, n);
+    for i in 0..n {
+        a[i][i] += n as f64;
+    }
+    let b: Vec<f64> = (0..n).map(|i| i as f64 / 10.0).collect();
+    let (_, x) = crate::lgs::lgs::solve(a.clone(), b.clone());
+    let ax = &a * &x;
+    for i in 0..n {
+        assert!((ax[i] - b[i]).abs() < 1e-9);
+    }
+    let product = &a * &crate::lgs::lgs::inverse(a.clone());
+    for i in 0..n {
+        for j in 0..n {
+            assert!((product[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_current_num_threads() {
+    assert!(parallel::current_num_threads() >= 1);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_configure_thread_pool_only_once() {
+    // the first call may race with other tests, but a second one always fails
+    let _ = parallel::configure_thread_pool(2);
+    assert!(matches!(
+        parallel::configure_thread_pool(3),
+        Err(parallel::ThreadPoolError::AlreadyConfigured)
+    ));
+}
@@ -0,0 +1,104 @@
+use crate::matrix::matrix::polynomial::format_polynomial;
+use crate::matrix::matrix::*;
+use crate::test_util::{assert_close, rationals};
+
+fn coeffs(c: &[i64]) -> Vec<Rational> {
+    c.iter().map(|&x| Rational::from(x)).collect()
+}
+
+#[test]
+fn test_char_poly() {
+    // eigenvalues 1, 2, 3
+    let m = rationals(vec![vec![2, 0, 0], vec![1, 1, 0], vec![0, 5, 3]]);
+    assert_eq!(m.char_poly(), coeffs(&[-6, 11, -6, 1]));
+
+    let m = rationals(vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(m.char_poly(), coeffs(&[-2, -5, 1]));
+}
+
+#[test]
+fn test_char_poly_trace_and_det() {
+    let m = rationals(vec![vec![2, -1, 4, 0], vec![3, 0, 1, 5], vec![-2, 7, 1, 1], vec![0, 3, -1, 2]]);
+    let p = m.char_poly();
+    assert_eq!(p.len(), 5);
+    assert_eq!(p[3], -Rational::from(5));
+    assert_eq!(p[0], m.det());
+}
+
+#[test]
+fn test_char_poly_needs_pivoting() {
+    // zero below the diagonal in the first column of the Hessenberg reduction
+    let m = rationals(vec![vec![1, 2, 3], vec![0, 4, 5], vec![7, 8, 0]]);
+    let p = m.char_poly();
+    assert_eq!(m.eval_polynomial(&p), Matrix::new(3, 3));
+    assert_eq!(p[0], -m.det());
+}
+
+#[test]
+fn test_cayley_hamilton_for_floats() {
+    let m = Matrix::from_data(vec![vec![0.5, 1.0, -2.0], vec![3.0, 0.0, 1.0], vec![1.0, -1.0, 2.0]]);
+    let zero = m.eval_polynomial(&m.char_poly());
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(zero[i][j].abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_char_poly_does_not_depend_on_scale() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 10.0]]);
+    let scaled = Matrix::from_data((0..3).map(|i| (0..3).map(|j| m[i][j] * 1e-17).collect()).collect());
+    // the coefficient of λ^k scales with s^(3 - k)
+    let p: Vec<f64> = scaled.char_poly().iter().enumerate().map(|(k, c)| c * 1e17_f64.powi(3 - k as i32)).collect();
+    assert_close(&p, &m.char_poly(), 1e-9);
+    assert_close(&p, &[3.0, -12.0, -16.0, 1.0], 1e-9);
+}
+
+#[test]
+fn test_char_poly_over_finite_field() {
+    let m: Matrix<Gf<5>> = Matrix::from_data(vec![vec![Gf::new(1), Gf::new(2)], vec![Gf::new(3), Gf::new(4)]]);
+    // x² - 5x - 2 = x² + 3 over GF(5)
+    assert_eq!(m.char_poly(), vec![Gf::new(3), Gf::new(0), Gf::new(1)]);
+    assert_eq!(m.eval_polynomial(&m.char_poly()), Matrix::new(2, 2));
+}
+
+#[test]
+fn test_minimal_poly() {
+    // diagonal with a repeated eigenvalue: (x - 2)(x - 3), while det(xI - A) = (x - 2)²(x - 3)
+    let m = rationals(vec![vec![2, 0, 0], vec![0, 2, 0], vec![0, 0, 3]]);
+    assert_eq!(m.minimal_poly(), coeffs(&[6, -5, 1]));
+    assert_eq!(m.char_poly(), coeffs(&[-12, 16, -7, 1]));
+
+    // a Jordan block needs the full power
+    let j = rationals(vec![vec![2, 1], vec![0, 2]]);
+    assert_eq!(j.minimal_poly(), coeffs(&[4, -4, 1]));
+
+    assert_eq!(rationals(vec![vec![5, 0], vec![0, 5]]).minimal_poly(), coeffs(&[-5, 1]));
+    assert_eq!(Matrix::<Rational>::new(3, 3).minimal_poly(), coeffs(&[0, 1]));
+}
+
+#[test]
+fn test_format_polynomial() {
+    assert_eq!(format_polynomial(&coeffs(&[-6, 11, -6, 1]), "x"), "x^3 - 6x^2 + 11x - 6");
+    assert_eq!(format_polynomial(&coeffs(&[0, -1, 0, -2]), "λ"), "-2λ^3 - λ");
+    assert_eq!(format_polynomial(&coeffs(&[1]), "x"), "1");
+    assert_eq!(format_polynomial(&coeffs(&[0, 0]), "x"), "0");
+    let half = vec![Rational::from(0), Rational::from(1) / Rational::from(2), Rational::from(1)];
+    assert_eq!(format_polynomial(&half, "x"), "x^2 + (1/2)x");
+    let fractions = vec![Rational::from(-1) / Rational::from(3), Rational::from(-5) / Rational::from(6), Rational::from(1)];
+    assert_eq!(format_polynomial(&fractions, "x"), "x^2 - (5/6)x - 1/3");
+    let complex = vec![Complex::new(1.0, -2.0), Complex::new(1.0, 0.0)];
+    assert_eq!(format_polynomial(&complex, "x"), "x + (1-2i)");
+}
+
+#[test]
+fn test_format_polynomial_rounds_floats() {
+    assert_eq!(format_polynomial(&[-0.01999999999999999, -0.5, 1.0], "x"), "x^2 - 0.5x - 0.02");
+    assert_eq!(format_polynomial(&[2.0000000000000004, 1e-17, 0.9999999999999998], "x"), "x^2 + 2");
+    assert_eq!(format_polynomial(&[1.5e-7, 1.0], "x"), "x + 0.00000015");
+    assert_eq!(format_polynomial(&[1.0, 1.234567890123456e14], "x"), "123456789000000x + 1");
+    assert_eq!(format_polynomial(&[1.0, 1e20], "x"), "100000000000000000000x");
+    let complex = vec![Complex::new(0.30000000000000004, -2.0000000000000004), Complex::new(1.0, 0.0)];
+    assert_eq!(format_polynomial(&complex, "x"), "x + (0.3-2i)");
+}
@@ -0,0 +1,99 @@
+use crate::matrix::matrix::*;
+
+fn m(data: Vec<Vec<f64>>) -> Matrix {
+    Matrix::from_data(data)
+}
+
+#[test]
+fn test_kron() {
+    let a = m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = m(vec![vec![0.0, 5.0], vec![6.0, 7.0]]);
+    assert_eq!(
+        a.kron(&b),
+        m(vec![
+            vec![0.0, 5.0, 0.0, 10.0],
+            vec![6.0, 7.0, 12.0, 14.0],
+            vec![0.0, 15.0, 0.0, 20.0],
+            vec![18.0, 21.0, 24.0, 28.0],
+        ])
+    );
+
+    let row = m(vec![vec![1.0, -1.0]]);
+    let k = row.kron(&a);
+    assert_eq!((k.height, k.width), (2, 4));
+}
+
+#[test]
+fn test_vec_identity_with_kron() {
+    // vec(A·X·B) = (Bᵀ ⊗ A)·vec(X)
+    let a = m(vec![vec![1.0, 2.0], vec![0.0, -1.0], vec![3.0, 1.0]]);
+    let x = m(vec![vec![2.0, 1.0, 0.0], vec![-1.0, 4.0, 2.0]]);
+    let b = m(vec![vec![1.0, 0.0], vec![2.0, 1.0], vec![0.0, 3.0]]);
+
+    let lhs = (&(&a * &x) * &b).vec();
+    let rhs = b.transpose().kron(&a) * &x.vec();
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_vec_unvec_round_trip() {
+    let a = m(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    assert_eq!(a.vec(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    assert_eq!(Matrix::unvec(&a.vec(), 2, 3), a);
+}
+
+#[test]
+#[should_panic(expected = "Vector length does not match the matrix dimensions")]
+fn test_unvec_length_mismatch_panics() {
+    Matrix::unvec(&[1.0, 2.0, 3.0], 2, 2);
+}
+
+#[test]
+fn test_hadamard_and_division() {
+    let a = m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = m(vec![vec![2.0, 4.0], vec![-1.0, 0.5]]);
+    assert_eq!(a.hadamard(&b), m(vec![vec![2.0, 8.0], vec![-3.0, 2.0]]));
+    assert_eq!(a.hadamard_div(&b), m(vec![vec![0.5, 0.5], vec![-3.0, 8.0]]));
+}
+
+#[test]
+#[should_panic(expected = "Matrix dimensions do not match")]
+fn test_hadamard_dimension_mismatch_panics() {
+    let a: Matrix = Matrix::new(2, 2);
+    a.hadamard(&Matrix::new(2, 3));
+}
+
+#[test]
+fn test_outer() {
+    assert_eq!(Matrix::outer(&[1.0, 2.0], &[3.0, 4.0, 5.0]), m(vec![vec![3.0, 4.0, 5.0], vec![6.0, 8.0, 10.0]]));
+}
+
+#[test]
+fn test_commutator() {
+    // Pauli matrices: [σx, σy] = 2i·σz
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    let sx = Matrix::from_data(vec![vec![c(0.0, 0.0), c(1.0, 0.0)], vec![c(1.0, 0.0), c(0.0, 0.0)]]);
+    let sy = Matrix::from_data(vec![vec![c(0.0, 0.0), c(0.0, -1.0)], vec![c(0.0, 1.0), c(0.0, 0.0)]]);
+    let sz = Matrix::from_data(vec![vec![c(1.0, 0.0), c(0.0, 0.0)], vec![c(0.0, 0.0), c(-1.0, 0.0)]]);
+    assert_eq!(sx.commutator(&sy), sz * c(0.0, 2.0));
+
+    let a = m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    assert_eq!(a.commutator(&a), Matrix::new(2, 2));
+}
+
+#[test]
+fn test_khatri_rao() {
+    let a = m(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let b = m(vec![vec![5.0, 6.0], vec![7.0, 8.0], vec![9.0, 10.0]]);
+    let k = a.khatri_rao(&b);
+    assert_eq!((k.height, k.width), (6, 2));
+    for j in 0..2 {
+        let col_a: Vec<f64> = (0..2).map(|i| a[i][j]).collect();
+        let col_b: Vec<f64> = (0..3).map(|i| b[i][j]).collect();
+        let expected = Matrix::from_data(col_a.iter().map(|x| vec![*x]).collect())
+            .kron(&Matrix::from_data(col_b.iter().map(|x| vec![*x]).collect()));
+        for i in 0..6 {
+            assert_eq!(k[i][j], expected[i][0]);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::lgs::lgs::{inverse, solve};
+use crate::matrix::matrix::{BigInt, Matrix, Rational};
+
+fn r(s: &str) -> Rational {
+    s.parse().unwrap()
+}
+
+fn matrix(rows: &[&[&str]]) -> Matrix<Rational> {
+    Matrix::from_data(rows.iter().map(|row| row.iter().map(|s| r(s)).collect()).collect())
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    let a: BigInt = "123456789012345678901234567890".parse().unwrap();
+    let b: BigInt = "-987654321098765432109876543210".parse().unwrap();
+    assert_eq!((&a + &b).to_string(), "-864197532086419753208641975320");
+    assert_eq!((&a * &b).to_string(), "-121932631137021795226185032733622923332237463801111263526900");
+    assert_eq!((&b / &a).to_string(), "-8");
+    assert_eq!((&b % &a).to_string(), "-9000000000900000000090");
+    assert_eq!(a.gcd(&b).to_string(), "9000000000900000000090");
+}
+
+#[test]
+fn test_bigint_division_by_multi_limb_divisor() {
+    let a: BigInt = "340282366920938463463374607431768211455".parse().unwrap();
+    let b: BigInt = "18446744073709551617".parse().unwrap();
+    let (q, rem) = a.div_rem(&b);
+    assert_eq!(q.to_string(), "18446744073709551615");
+    assert!(rem.is_zero());
+    assert_eq!(&q * &b + rem, a);
+}
+
+#[test]
+fn test_rational_is_reduced() {
+    let x = Rational::new(BigInt::from(6), BigInt::from(-4));
+    assert_eq!(x.to_string(), "-3/2");
+    assert_eq!(x, r("-1.5"));
+    assert_eq!(r("14/6").to_string(), "7/3");
+    assert_eq!(r("2.5e-1"), r("1/4"));
+    assert_eq!(r("4/2").to_string(), "2");
+}
+
+#[test]
+fn test_rational_exponents() {
+    assert_eq!(r("1.5e3"), r("1500"));
+    assert_eq!(r("1e-20").to_string(), format!("1/1{}", "0".repeat(20)));
+    assert_eq!(r("1e10000").to_string().len(), 10_001);
+    assert!("1e10001".parse::<Rational>().is_err());
+    assert!("1e2000000000".parse::<Rational>().is_err());
+    assert!("1.5e-2147483648".parse::<Rational>().is_err());
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    assert_eq!(r("1/3") + r("1/6"), r("1/2"));
+    assert_eq!(r("1/3") - r("1/2"), r("-1/6"));
+    assert_eq!(r("2/3") * r("9/4"), r("3/2"));
+    assert_eq!(r("2/3") / r("4/9"), r("3/2"));
+    assert!(r("1/3") < r("0.34"));
+}
+
+#[test]
+fn test_parse_invalid_rational() {
+    assert!("".parse::<Rational>().is_err());
+    assert!("1/0".parse::<Rational>().is_err());
+    assert!("a/2".parse::<Rational>().is_err());
+}
+
+#[test]
+fn test_create_rational_matrix_from_json_str() {
+    let m: Matrix<Rational> = Matrix::from_str("[[1/3, 2],[0.5, -1]]");
+    assert_eq!(m, matrix(&[&["1/3", "2"], &["1/2", "-1"]]));
+}
+
+#[test]
+fn test_solve_rational_lgs_exactly() {
+    let m = matrix(&[&["2", "-1"], &["4", "1"]]);
+    let (m, res) = solve(m, vec![r("6"), r("6")]);
+    assert_eq!(res, vec![r("2"), r("-2")]);
+    assert_eq!(m, matrix(&[&["1", "0"], &["0", "1"]]));
+}
+
+#[test]
+fn test_solve_rational_lgs_with_fraction_result() {
+    let m = matrix(&[&["3", "0"], &["1", "1"]]);
+    let (_, res) = solve(m, vec![r("7"), r("3")]);
+    assert_eq!(res, vec![r("7/3"), r("2/3")]);
+}
+
+#[test]
+fn test_inverse_of_rational_matrix() {
+    let m = matrix(&[&["2", "-1"], &["4", "1"]]);
+    let inv = inverse(m.clone());
+    assert_eq!(inv, matrix(&[&["1/6", "1/6"], &["-2/3", "1/3"]]));
+    assert_eq!(m * inv, matrix(&[&["1", "0"], &["0", "1"]]));
+}
+
+#[test]
+fn test_det_of_rational_matrix() {
+    let m = matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]);
+    assert_eq!(m.det(), r("1/60"));
+}
@@ -0,0 +1,169 @@
+use crate::matrix::matrix::*;
+
+fn ratio(n: i64, d: i64) -> Rational {
+    Rational::from(n) / Rational::from(d)
+}
+
+fn matrix() -> Matrix {
+    Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 10.0]])
+}
+
+#[test]
+fn test_scale_row() {
+    let mut m = matrix();
+    m.scale_row(1, 0.5);
+    assert_eq!(m[1], vec![2.0, 2.5, 3.0]);
+    assert_eq!(m[0], vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_add_row_multiple() {
+    let mut m = matrix();
+    m.add_row_multiple(0, 2, -7.0);
+    assert_eq!(m[2], vec![0.0, -6.0, -11.0]);
+    m.add_row_multiple(2, 0, 1.0);
+    assert_eq!(m[0], vec![1.0, -4.0, -8.0]);
+}
+
+#[test]
+#[should_panic(expected = "Source and target must differ")]
+fn test_add_row_to_itself_panics() {
+    matrix().add_row_multiple(1, 1, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Source and target must differ")]
+fn test_add_column_to_itself_panics() {
+    matrix().add_column_multiple(2, 2, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Row or column index out of bounds")]
+fn test_add_row_multiple_out_of_bounds_panics() {
+    matrix().add_row_multiple(0, 3, 1.0);
+}
+
+#[test]
+fn test_checked_swap_rows() {
+    let mut m = matrix();
+    assert_eq!(m.checked_swap_rows(0, 3), None);
+    assert_eq!(m.get_data(), matrix().get_data());
+    assert_eq!(m.checked_swap_rows(0, 2), Some(()));
+    assert_eq!(m[0], vec![7.0, 8.0, 10.0]);
+}
+
+#[test]
+fn test_column_operations() {
+    let mut m = matrix();
+    m.swap_columns(0, 2);
+    assert_eq!(m[0], vec![3.0, 2.0, 1.0]);
+    m.scale_column(1, 2.0);
+    assert_eq!(m[1], vec![6.0, 10.0, 4.0]);
+    m.add_column_multiple(2, 0, -3.0);
+    assert_eq!(m[2], vec![-11.0, 16.0, 7.0]);
+    assert_eq!(m.checked_swap_columns(1, 5), None);
+}
+
+#[test]
+fn test_elementary_matrices_from_the_left_apply_row_operations() {
+    let a = matrix();
+    let mut m = a.clone();
+    m.add_row_multiple(0, 2, -7.0);
+    assert_eq!((&Matrix::elementary_add(3, 0, 2, -7.0) * &a).get_data(), m.get_data());
+
+    let mut m = a.clone();
+    m.swap_rows(0, 1);
+    assert_eq!((&Matrix::elementary_swap(3, 0, 1) * &a).get_data(), m.get_data());
+
+    let mut m = a.clone();
+    m.scale_row(2, 3.0);
+    assert_eq!((&Matrix::elementary_scale(3, 2, 3.0) * &a).get_data(), m.get_data());
+}
+
+#[test]
+fn test_elementary_matrices_from_the_right_apply_column_operations() {
+    let a = matrix();
+    let mut m = a.clone();
+    m.add_column_multiple(0, 2, -7.0);
+    let e = Matrix::elementary_add(3, 0, 2, -7.0).transpose();
+    assert_eq!((&a * &e).get_data(), m.get_data());
+
+    let mut m = a.clone();
+    m.swap_columns(0, 1);
+    assert_eq!((&a * &Matrix::elementary_swap(3, 0, 1)).get_data(), m.get_data());
+}
+
+#[test]
+fn test_row_op_inverse() {
+    let ops = vec![
+        RowOp::Swap(0, 2),
+        RowOp::Scale { row: 1, factor: ratio(2, 3) },
+        RowOp::AddMultiple { target: 2, source: 0, factor: Rational::from(-4) },
+    ];
+    let a: Matrix<Rational> = Matrix::from_str("[[1, 2, 3], [4, 5, 6], [7, 8, 10]]");
+    for op in ops {
+        let mut m = a.clone();
+        op.apply(&mut m);
+        op.inverse().unwrap().apply(&mut m);
+        assert_eq!(m, a);
+    }
+    assert_eq!(RowOp::Scale { row: 0, factor: 0.0 }.inverse(), None);
+}
+
+#[test]
+fn test_row_op_log_replay_and_undo() {
+    let a: Matrix<Rational> = Matrix::from_str("[[2, 1, 1], [4, 3, 3], [8, 7, 9]]");
+    let mut m = a.clone();
+    let mut log = RowOpLog::new();
+    log.apply(RowOp::AddMultiple { target: 1, source: 0, factor: Rational::from(-2) }, &mut m);
+    log.apply(RowOp::AddMultiple { target: 2, source: 0, factor: Rational::from(-4) }, &mut m);
+    log.apply(RowOp::AddMultiple { target: 2, source: 1, factor: Rational::from(-3) }, &mut m);
+    log.apply(RowOp::Scale { row: 0, factor: ratio(1, 2) }, &mut m);
+    assert_eq!(log.len(), 4);
+    assert_eq!(m, Matrix::from_str("[[1, 1/2, 1/2], [0, 1, 1], [0, 0, 2]]"));
+
+    let mut replayed = a.clone();
+    log.replay(&mut replayed);
+    assert_eq!(replayed, m);
+    assert_eq!(&log.matrix(3) * &a, m);
+
+    let mut undone = m.clone();
+    log.undo(&mut undone).unwrap();
+    assert_eq!(undone, a);
+    assert_eq!(&log.inverse().unwrap().matrix(3) * &log.matrix(3), Matrix::identity(3));
+
+    assert_eq!(log.undo_last(&mut m), Some(RowOp::Scale { row: 0, factor: ratio(1, 2) }));
+    assert_eq!(log.len(), 3);
+    assert_eq!(m[0], vec![Rational::from(2), Rational::from(1), Rational::from(1)]);
+}
+
+#[test]
+fn test_row_op_log_with_scaling_by_zero_cannot_be_undone() {
+    let mut m = matrix();
+    let mut log = RowOpLog::new();
+    log.apply(RowOp::Swap(0, 1), &mut m);
+    log.apply(RowOp::Scale { row: 2, factor: 0.0 }, &mut m);
+    let before = m.clone();
+    assert_eq!(log.undo(&mut m), None);
+    assert_eq!(log.undo_last(&mut m), None);
+    assert_eq!(m.get_data(), before.get_data());
+    assert_eq!(log.len(), 2);
+}
+
+#[test]
+fn test_row_op_log_with_self_add_cannot_be_undone() {
+    let self_add = RowOp::AddMultiple { target: 0, source: 0, factor: 1.0 };
+    assert_eq!(self_add.inverse(), None);
+
+    let mut m = Matrix::from_data(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let mut log = RowOpLog::new();
+    let applied = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| log.apply(self_add.clone(), &mut m)));
+    assert!(applied.is_err());
+    assert!(log.is_empty());
+
+    log.apply(RowOp::Swap(0, 1), &mut m);
+    log.push(self_add);
+    assert_eq!(log.undo(&mut m), None);
+    assert_eq!(log.undo_last(&mut m), None);
+    assert_eq!(m.get_data(), vec![vec![3.0, 4.0], vec![1.0, 2.0]]);
+}
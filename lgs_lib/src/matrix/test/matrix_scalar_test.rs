@@ -0,0 +1,71 @@
+use crate::lgs::lgs::solve;
+use crate::matrix::matrix::{Complex, Matrix, Scalar};
+
+#[test]
+fn test_create_f32_matrix_from_json_str() {
+    let m: Matrix<f32> = Matrix::from_str("[[2.0,2.0],[3.0,4.0]]");
+    let expected = Matrix::from_data(vec![vec![2_f32, 2_f32], vec![3_f32, 4_f32]]);
+    assert_eq!(m, expected)
+}
+
+#[test]
+fn test_new_non_square_matrix() {
+    let m: Matrix<f32> = Matrix::new(2, 3);
+    assert_eq!(m.get_data(), vec![vec![0_f32; 3]; 2]);
+}
+
+#[test]
+fn test_transpose_non_square_matrix() {
+    let m = Matrix::from_data(vec![vec![1_f32, 2_f32, 3_f32]]);
+    let expected = Matrix::from_data(vec![vec![1_f32], vec![2_f32], vec![3_f32]]);
+    assert_eq!(m.transpose(), expected);
+}
+
+#[test]
+fn test_det_of_f32_matrix() {
+    let m = Matrix::from_data(vec![vec![1_f32, 2_f32], vec![3_f32, 4_f32]]);
+    assert_eq!(m.det(), -2_f32);
+}
+
+#[test]
+fn test_solve_f32_lgs() {
+    let m = Matrix::from_data(vec![vec![2_f32, -1_f32], vec![4_f32, 1_f32]]);
+    let (_, res) = solve(m, vec![6_f32, 6_f32]);
+    assert_eq!(res, vec![2_f32, -2_f32]);
+}
+
+#[test]
+fn test_solve_with_zero_pivot() {
+    let m = Matrix::from_data(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+    let (m, res) = solve(m, vec![2.0, 3.0]);
+    assert_eq!(res, vec![3.0, 2.0]);
+    assert_eq!(m, Matrix::from_data(vec![vec![1.0, 0.0], vec![0.0, 1.0]]));
+}
+
+#[test]
+fn test_complex_arithmetic() {
+    let a = Complex::new(1.0, 2.0);
+    let b = Complex::new(3.0, -1.0);
+    assert_eq!(a + b, Complex::new(4.0, 1.0));
+    assert_eq!(a * b, Complex::new(5.0, 5.0));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(Complex::new(3.0, 4.0).abs(), 5.0);
+}
+
+#[test]
+fn test_det_of_complex_matrix() {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::one();
+    let m = Matrix::from_data(vec![vec![one, i], vec![i, one]]);
+    assert_eq!(m.det(), Complex::new(2.0, 0.0));
+}
+
+#[test]
+fn test_solve_complex_lgs() {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::one();
+    let m = Matrix::from_data(vec![vec![one, i], vec![i, one]]);
+    // x = [1, i]  =>  b = [1 + i·i, i + i] = [0, 2i]
+    let (_, res) = solve(m, vec![Complex::zero(), Complex::new(0.0, 2.0)]);
+    assert_eq!(res, vec![one, i]);
+}
@@ -0,0 +1,80 @@
+use crate::matrix::matrix::smatrix::DimensionMismatch;
+use crate::matrix::matrix::{Matrix, SMatrix, SMatrix2, SMatrix3, SMatrix4};
+
+fn assert_close<const N: usize>(actual: SMatrix<N, N>, expected: SMatrix<N, N>) {
+    for i in 0..N {
+        for j in 0..N {
+            assert!((actual[i][j] - expected[i][j]).abs() < 1e-12, "{:?} != {:?}", actual, expected);
+        }
+    }
+}
+
+fn create_4x4_matrix() -> SMatrix4 {
+    SMatrix::new([
+        [1.0, 2.0, 52.0, 2.0],
+        [1.0, 0.0, 1.0, 2.0],
+        [3.0, 2.0, 12.0, 2.0],
+        [3.0, 5.0, 3.0, 2.0],
+    ])
+}
+
+#[test]
+fn test_smatrix_multiply_different_size() {
+    let m1 = SMatrix::new([[1.0, 2.0]]);
+    let m2 = SMatrix::new([[5.0], [7.0]]);
+    assert_eq!(m1 * m2, SMatrix::new([[19.0]]));
+    assert_eq!(m2 * m1, SMatrix::new([[5.0, 10.0], [7.0, 14.0]]));
+}
+
+#[test]
+fn test_smatrix_multiply_matches_matrix() {
+    let m1 = SMatrix2::new([[1.0, 2.0], [3.0, 4.0]]);
+    let m2 = SMatrix2::new([[5.0, 6.0], [7.0, 8.0]]);
+    let dynamic = Matrix::from(m1) * Matrix::from(m2);
+    assert_eq!(Matrix::from(m1 * m2), dynamic);
+}
+
+#[test]
+fn test_smatrix_transpose_and_mul_vec() {
+    let m = SMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(m.transpose(), SMatrix::new([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+    assert_eq!(m.mul_vec(&[1.0, 0.0, -1.0]), [-2.0, -2.0]);
+}
+
+#[test]
+fn test_smatrix_det_closed_form() {
+    assert_eq!(SMatrix::new([[3.0]]).det(), 3.0);
+    assert_eq!(SMatrix2::new([[1.0, 2.0], [3.0, 4.0]]).det(), -2.0);
+    assert_eq!(SMatrix3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]).det(), 6.0);
+    assert_eq!(create_4x4_matrix().det(), 684.0);
+}
+
+#[test]
+fn test_smatrix_inverse_closed_form() {
+    let m2 = SMatrix2::new([[2.0, 1.0], [4.0, 3.0]]);
+    assert_close(m2 * m2.inverse().unwrap(), SMatrix2::identity());
+
+    let m3 = SMatrix3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+    assert_close(m3 * m3.inverse().unwrap(), SMatrix3::identity());
+
+    let m4 = create_4x4_matrix();
+    assert_close(m4 * m4.inverse().unwrap(), SMatrix4::identity());
+    assert_close(m4.inverse().unwrap() * m4, SMatrix4::identity());
+}
+
+#[test]
+fn test_smatrix_inverse_of_singular_matrix() {
+    assert_eq!(SMatrix2::new([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+    assert_eq!(SMatrix3::new([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0], [3.0, 3.0, 3.0]]).inverse(), None);
+}
+
+#[test]
+fn test_smatrix_conversion() {
+    let m = Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let s: SMatrix<2, 3> = SMatrix::try_from(&m).unwrap();
+    assert_eq!(s, SMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    assert_eq!(Matrix::from(s), m);
+
+    let err = SMatrix3::try_from(m).unwrap_err();
+    assert_eq!(err, DimensionMismatch { expected: (3, 3), found: (2, 3) });
+}
@@ -0,0 +1,98 @@
+use crate::lgs::lgs::{self, Solve};
+use crate::matrix::matrix::*;
+use crate::test_util::assert_close;
+
+#[test]
+fn test_diagonal_matrix() {
+    let d = DiagonalMatrix::new(vec![2.0, 4.0, -1.0]);
+    assert_eq!(d.det(), -8.0);
+    assert_eq!(d.mul_vec(&[1.0, 1.0, 1.0]), vec![2.0, 4.0, -1.0]);
+    assert_eq!(Solve::solve(&d, &[2.0, 2.0, 3.0]), vec![1.0, 0.5, -3.0]);
+    assert_eq!(d.inverse().unwrap().diagonal(), &[0.5, 0.25, -1.0]);
+    assert!(DiagonalMatrix::new(vec![1.0, 0.0]).inverse().is_none());
+
+    let m = Matrix::from(d.clone());
+    assert_eq!(DiagonalMatrix::from_matrix(&m), Some(d));
+    assert_eq!(DiagonalMatrix::from_matrix(&Matrix::from_data(vec![vec![1.0, 1.0], vec![0.0, 1.0]])), None);
+}
+
+#[test]
+fn test_triangular_substitution() {
+    let m: Matrix = Matrix::from_data(vec![vec![2.0, 1.0, -1.0], vec![0.0, 3.0, 2.0], vec![0.0, 0.0, 4.0]]);
+    let u = UpperTriangular::from_matrix(&m).unwrap();
+    assert_eq!(u.det(), 24.0);
+    assert_eq!(u.get(2, 0), 0.0);
+    assert_eq!(u.get(1, 2), 2.0);
+    assert_eq!(Matrix::from(u.clone()), m);
+
+    let x = [1.0, -2.0, 0.5];
+    let b = u.mul_vec(&x);
+    assert_eq!(b, (m.clone() * &Vector::from_data(x.to_vec())).into_data());
+    assert_close(&u.back_substitute(&b), &x, 1e-12);
+
+    let l = u.transpose();
+    assert_eq!(Matrix::from(l.clone()), m.transpose());
+    let b = l.mul_vec(&x);
+    assert_close(&l.forward_substitute(&b), &x, 1e-12);
+    assert_close(&Solve::solve(&l, &b), &x, 1e-12);
+
+    assert!(UpperTriangular::from_matrix(&m.transpose()).is_none());
+    assert!(LowerTriangular::from_matrix(&m).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_triangular_singular_panics() {
+    let m: Matrix = Matrix::from_data(vec![vec![1.0, 1.0], vec![0.0, 0.0]]);
+    UpperTriangular::from_matrix(&m).unwrap().back_substitute(&[1.0, 1.0]);
+}
+
+#[test]
+fn test_symmetric_packed_storage() {
+    let m: Matrix = Matrix::from_data(vec![vec![4.0, 1.0, 2.0], vec![1.0, 3.0, 0.0], vec![2.0, 0.0, 5.0]]);
+    let s = SymmetricMatrix::from_matrix(&m).unwrap();
+    assert_eq!(s.get(0, 2), s.get(2, 0));
+    assert_eq!(Matrix::from(s.clone()), m);
+
+    let x = [1.0, 2.0, 3.0];
+    assert_eq!(s.mul_vec(&x), (m.clone() * &Vector::from_data(x.to_vec())).into_data());
+    assert_close(&Solve::solve(&s, &s.mul_vec(&x)), &x, 1e-12);
+
+    let mut t: SymmetricMatrix = SymmetricMatrix::new(2);
+    t.set(0, 1, 7.0);
+    assert_eq!(t.get(1, 0), 7.0);
+
+    assert!(SymmetricMatrix::from_matrix(&Matrix::from_data(vec![vec![1.0, 2.0], vec![3.0, 1.0]])).is_none());
+}
+
+#[test]
+fn test_banded_matrix() {
+    let m: Matrix = Matrix::from_data(vec![
+        vec![4.0, 1.0, 0.0, 0.0],
+        vec![2.0, 4.0, 1.0, 0.0],
+        vec![0.0, 2.0, 4.0, 1.0],
+        vec![0.0, 0.0, 2.0, 4.0],
+    ]);
+    let b = BandedMatrix::from_matrix(&m, 1, 1).unwrap();
+    assert_eq!((b.kl(), b.ku()), (1, 1));
+    assert_eq!(b.get(3, 0), 0.0);
+    assert_eq!(Matrix::from(b.clone()), m);
+    assert!(BandedMatrix::from_matrix(&m, 0, 1).is_none());
+
+    let x = [1.0, -1.0, 2.0, 0.5];
+    let rhs = b.mul_vec(&x);
+    assert_eq!(rhs, (m.clone() * &Vector::from_data(x.to_vec())).into_data());
+    assert_close(&Solve::solve(&b, &rhs), &x, 1e-12);
+
+    // the structured types can be passed to the dense solvers as well
+    let (_, y) = lgs::solve(b.clone(), rhs.clone());
+    assert_close(&y, &x, 1e-12);
+    assert_close(&lgs::solve_lu(b, rhs), &x, 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "Position is outside the band")]
+fn test_banded_set_outside_band_panics() {
+    let mut b: BandedMatrix = BandedMatrix::new(3, 0, 1);
+    b.set(1, 0, 1.0);
+}
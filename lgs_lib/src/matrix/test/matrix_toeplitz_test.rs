@@ -0,0 +1,109 @@
+use crate::lgs::lgs::{self, Solve};
+use crate::matrix::matrix::fft::{fft, ifft};
+use crate::matrix::matrix::*;
+use std::f64::consts::PI;
+use crate::test_util::assert_close;
+
+fn naive_dft(x: &[Complex]) -> Vec<Complex> {
+    let n = x.len();
+    (0..n)
+        .map(|k| {
+            x.iter().enumerate().fold(Complex::default(), |acc, (j, x)| {
+                acc + *x * Complex::from_polar(1.0, -2.0 * PI * (j * k) as f64 / n as f64)
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_fft_matches_dft_for_all_lengths() {
+    for n in [1, 2, 3, 5, 8, 12, 17, 64] {
+        let x: Vec<Complex> = (0..n).map(|i| Complex::new(i as f64 * 0.5 - 1.0, (i % 3) as f64)).collect();
+        let mut y = x.clone();
+        fft(&mut y);
+        for (a, e) in y.iter().zip(naive_dft(&x)) {
+            assert!((*a - e).abs() < 1e-9, "n = {}", n);
+        }
+        ifft(&mut y);
+        for (a, e) in y.iter().zip(&x) {
+            assert!((*a - *e).abs() < 1e-12, "n = {}", n);
+        }
+    }
+}
+
+#[test]
+fn test_toeplitz_levinson_nonsymmetric() {
+    let t = ToeplitzMatrix::new(vec![4.0, 1.0, 0.5, 0.2], vec![4.0, -1.0, 2.0, 0.3]);
+    let m = Matrix::from(t.clone());
+    assert_eq!(m[2][0], 0.5);
+    assert_eq!(m[0][2], 2.0);
+    assert_eq!(m[3][1], t.get(3, 1));
+
+    let x = [1.0, -2.0, 0.5, 3.0];
+    let b = t.mul_vec(&x);
+    assert_eq!(b, (m * &Vector::from_data(x.to_vec())).into_data());
+    assert_close(&t.levinson(&b).unwrap(), &x, 1e-9);
+}
+
+#[test]
+fn test_toeplitz_autocorrelation_matches_dense_inverse() {
+    let r: Vec<f64> = (0..12).map(|k| 0.8_f64.powi(k)).collect();
+    let t = ToeplitzMatrix::symmetric(r);
+    let b: Vec<f64> = (0..12).map(|i| (i as f64).cos()).collect();
+
+    let expected = lgs::inverse(t.clone()) * &Vector::from_data(b.clone());
+    assert_close(&Solve::solve(&t, &b), &expected, 1e-9);
+}
+
+#[test]
+fn test_toeplitz_singular_leading_minor_falls_back() {
+    // the leading 1×1 block is zero, the whole matrix is not singular
+    let t = ToeplitzMatrix::new(vec![0.0, 1.0], vec![0.0, 2.0]);
+    assert!(t.levinson(&[2.0, 1.0]).is_none());
+    assert_close(&Solve::solve(&t, &[2.0, 1.0]), &[1.0, 1.0], 1e-9);
+}
+
+#[test]
+fn test_toeplitz_exact_for_rationals() {
+    let r = |v: i64| Rational::from(v);
+    let t = ToeplitzMatrix::new(vec![r(3), r(1), r(-2)], vec![r(3), r(2), r(5)]);
+    let x = vec![r(1), r(2), r(-1)];
+    assert_eq!(t.levinson(&t.mul_vec(&x)).unwrap(), x);
+}
+
+#[test]
+fn test_circulant_multiply_and_solve() {
+    for n in [4, 7] {
+        let c = CirculantMatrix::new((0..n).map(|i| (i * i % 5) as f64 + 1.0).collect());
+        let m = Matrix::from(c.clone());
+        assert_eq!(m[1][0], c.first_col()[1]);
+        assert_eq!(m[0][1], c.first_col()[n - 1]);
+
+        let x: Vec<f64> = (0..n).map(|i| i as f64 - 1.5).collect();
+        let b = c.mul_vec(&x);
+        assert_close(&b, &(m.clone() * &Vector::from_data(x.clone())), 1e-9);
+        assert_close(&c.solve(&b), &x, 1e-9);
+        assert!((c.det() - m.det()).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_circulant_complex() {
+    let c = CirculantMatrix::new(vec![Complex::new(2.0, 1.0), Complex::i(), Complex::new(-1.0, 0.0)]);
+    let x = vec![Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(2.0, 2.0)];
+    let b = c.mul_vec(&x);
+    let expected = Matrix::from(c.clone()) * &Vector::from_data(x.clone());
+    for (a, e) in b.iter().zip(expected.iter()) {
+        assert!((*a - *e).abs() < 1e-12);
+    }
+    for (a, e) in Solve::solve(&c, &b).iter().zip(&x) {
+        assert!((*a - *e).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Matrix is singular")]
+fn test_circulant_singular_panics() {
+    // all rows equal, the eigenvalues but the first are zero
+    CirculantMatrix::new(vec![1.0, 1.0, 1.0]).solve(&[1.0, 2.0, 3.0]);
+}
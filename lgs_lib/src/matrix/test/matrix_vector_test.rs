@@ -0,0 +1,82 @@
+use crate::lgs::lgs::{solve, solve_lu};
+use crate::matrix::matrix::{Complex, Matrix, Rational, Vector};
+
+#[test]
+fn test_vector_dot_and_cross() {
+    let a = Vector::from_data(vec![1.0, 2.0, 3.0]);
+    let b = Vector::from_data(vec![4.0, 5.0, 6.0]);
+    assert_eq!(a.dot(&b), 32.0);
+    assert_eq!(a.cross(&b), vec![-3.0, 6.0, -3.0]);
+    assert_eq!(a.cross(&b).dot(&a), 0.0);
+}
+
+#[test]
+fn test_vector_complex_dot_conjugates() {
+    let a = Vector::from_data(vec![Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)]);
+    assert_eq!(a.dot(&a), Complex::new(2.0, 0.0));
+}
+
+#[test]
+fn test_vector_norms() {
+    let v: Vector = Vector::from_data(vec![3.0, -4.0]);
+    assert_eq!(v.norm_1(), 7.0);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.norm_inf(), 4.0);
+    assert!((v.norm_p(2.0) - 5.0).abs() < 1e-12);
+    assert_eq!(v.norm_p(f64::INFINITY), 4.0);
+    let n = v.normalize().unwrap();
+    assert!((n[0] - 0.6).abs() < 1e-12 && (n[1] + 0.8).abs() < 1e-12);
+    assert!(Vector::<f64>::new(3).normalize().is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_vector_norm_p_below_one_panics() {
+    Vector::from_data(vec![1.0]).norm_p(0.5);
+}
+
+#[test]
+fn test_vector_outer_and_axpy() {
+    let a = Vector::from_data(vec![1.0, 2.0]);
+    let b = Vector::from_data(vec![3.0, 4.0, 5.0]);
+    assert_eq!(
+        a.outer(&b),
+        Matrix::from_data(vec![vec![3.0, 4.0, 5.0], vec![6.0, 8.0, 10.0]])
+    );
+
+    let mut y = Vector::from_data(vec![1.0, 1.0]);
+    y.axpy(2.0, &a);
+    assert_eq!(y, vec![3.0, 5.0]);
+}
+
+#[test]
+fn test_matrix_vector_products() {
+    let m: Matrix = Matrix::from_data(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let x = Vector::from_data(vec![1.0, 0.0, -1.0]);
+    assert_eq!(m.clone() * &x, vec![-2.0, -2.0]);
+
+    let y = Vector::from_data(vec![1.0, 1.0]);
+    assert_eq!(&y * m, vec![5.0, 7.0, 9.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_vector_dimension_mismatch_panics() {
+    let m: Matrix = Matrix::new(2, 3);
+    let _ = m * &Vector::from_data(vec![1.0, 2.0]);
+}
+
+#[test]
+fn test_solvers_accept_vectors() {
+    let m = Matrix::from_data(vec![
+        vec![Rational::from(2), Rational::from(1)],
+        vec![Rational::from(1), Rational::from(3)],
+    ]);
+    let b: Vector<Rational> = vec![Rational::from(3), Rational::from(5)].into();
+
+    let x = solve_lu(m.clone(), b.clone());
+    assert_eq!(m.clone() * &x, b);
+
+    let (_, y) = solve(m, b);
+    assert_eq!(x, y);
+}
@@ -0,0 +1,167 @@
+//! # Description:
+//! Linear systems of ordinary differential equations `x' = A·x + b` with constant coefficients,
+//! solved in closed form with the matrix exponential or the eigendecomposition of `A`.
+
+use crate::lgs::lgs::{eigen_decompose, fft::FftScalar, Complex, EigenDecomposition, Matrix, Scalar, Vector};
+use std::marker::PhantomData;
+
+/// # Linear system
+///
+/// Solves the homogeneous system `x' = A·x` with `x(0) = x0`,
+/// returning the states `x(t) = e^(A·t)·x0` at the requested times.
+///
+/// # Parameters
+///
+/// a: the square system matrix
+/// x0: the initial state
+/// times: the times to evaluate the solution at, negative times run backwards
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square or does not match the length of `x0`.
+pub fn linear_system<T: Scalar>(a: &Matrix<T>, x0: &[T], times: &[f64]) -> Vec<Vector<T>> {
+    check_dimensions(a, x0);
+    let x0 = Vector::from(x0);
+    times.iter().map(|&t| (a.clone() * T::from_f64(t)).expm() * &x0).collect()
+}
+
+/// # Forced linear system
+///
+/// Solves `x' = A·x + b` with a constant forcing `b` and `x(0) = x0`,
+/// returning the states `x(t) = e^(A·t)·x0 + ∫₀ᵗ e^(A·s) ds·b` at the requested times.
+///
+/// The integral is read off the exponential of the augmented matrix `[[A, b], [0, 0]]`,
+/// so `A` does not need to be invertible.
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square or does not match the lengths of `b` and `x0`.
+pub fn forced_linear_system<T: Scalar>(a: &Matrix<T>, b: &[T], x0: &[T], times: &[f64]) -> Vec<Vector<T>> {
+    check_dimensions(a, x0);
+    check_dimensions(a, b);
+    let n = a.height;
+    let mut column = Matrix::new(n, 1);
+    for (i, x) in b.iter().enumerate() {
+        column[i][0] = x.clone();
+    }
+    let augmented = Matrix::block(&[&[a, &column], &[&Matrix::new(1, n), &Matrix::new(1, 1)]]);
+    let mut start = x0.to_vec();
+    start.push(T::one());
+    let start = Vector::from(start);
+
+    times
+        .iter()
+        .map(|&t| {
+            let x = (augmented.clone() * T::from_f64(t)).expm() * &start;
+            x.into_data().into_iter().take(n).collect()
+        })
+        .collect()
+}
+
+/// # Modal solution
+/// The closed form solution `x(t) = Σ_k z_k(t)·v_k` of `x' = A·x + b` in the eigenvectors `v_k` of `A`.
+/// Each mode evolves on its own, `z_k' = λ_k·z_k + d_k`, which gives
+/// `z_k(t) = e^(λ_k·t)·c_k + (e^(λ_k·t) - 1)/λ_k·d_k` with `c = V⁻¹·x0` and `d = V⁻¹·b`.
+#[derive(Debug, Clone)]
+pub struct ModalSolution<T = f64> {
+    decomposition: EigenDecomposition,
+    initial: Vec<Complex>,
+    forcing: Vec<Complex>,
+    scalar: PhantomData<T>,
+}
+
+impl<T: FftScalar> ModalSolution<T> {
+    /// The eigenvalues of `A`, the rates of the modes.
+    pub fn eigenvalues(&self) -> &[Complex] {
+        self.decomposition.eigenvalues()
+    }
+
+    /// The modes, the eigenvectors of `A`, as columns.
+    pub fn modes(&self) -> &Matrix<Complex> {
+        self.decomposition.eigenvectors()
+    }
+
+    /// The coordinates `c = V⁻¹·x0` of the initial state in the modes.
+    pub fn initial_coordinates(&self) -> &[Complex] {
+        &self.initial
+    }
+
+    /// The state at time `t`. Real types drop the imaginary part,
+    /// which is zero up to rounding for real systems.
+    pub fn state(&self, t: f64) -> Vector<T> {
+        let z: Vec<Complex> = self
+            .eigenvalues()
+            .iter()
+            .zip(self.initial.iter().zip(&self.forcing))
+            .map(|(&l, (&c, &d))| exp(l * Complex::from(t)) * c + phi(l, t) * d)
+            .collect();
+        (self.modes() * &Vector::from(z)).into_iter().map(T::from_complex).collect()
+    }
+
+    /// The states at the requested times.
+    pub fn states(&self, times: &[f64]) -> Vec<Vector<T>> {
+        times.iter().map(|&t| self.state(t)).collect()
+    }
+}
+
+/// # Modal decomposition
+///
+/// The closed form solution of `x' = A·x` with `x(0) = x0` in the eigenvectors of `A`.
+///
+/// Returns `None` if `A` is not diagonalizable, use [`linear_system`] instead.
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square or does not match the length of `x0`.
+pub fn modal_decomposition<T: FftScalar>(a: &Matrix<T>, x0: &[T]) -> Option<ModalSolution<T>> {
+    forced_modal_decomposition(a, &vec![T::zero(); a.height], x0)
+}
+
+/// # Forced modal decomposition
+///
+/// The closed form solution of `x' = A·x + b` with `x(0) = x0` in the eigenvectors of `A`.
+///
+/// Returns `None` if `A` is not diagonalizable, use [`forced_linear_system`] instead.
+///
+/// # Panics if
+///
+/// Panics if the matrix is not square or does not match the lengths of `b` and `x0`.
+pub fn forced_modal_decomposition<T: FftScalar>(a: &Matrix<T>, b: &[T], x0: &[T]) -> Option<ModalSolution<T>> {
+    check_dimensions(a, x0);
+    check_dimensions(a, b);
+    let decomposition = eigen_decompose(a)?;
+    let to_complex = |x: &[T]| x.iter().map(|x| x.to_complex()).collect::<Vec<_>>();
+    Some(ModalSolution {
+        initial: decomposition.coordinates(&to_complex(x0)),
+        forcing: decomposition.coordinates(&to_complex(b)),
+        decomposition,
+        scalar: PhantomData,
+    })
+}
+
+fn check_dimensions<T>(a: &Matrix<T>, x: &[T]) {
+    if a.width != a.height {
+        panic!("Matrix must be square");
+    }
+    if x.len() != a.height {
+        panic!("Matrix and vector lengths do not match");
+    }
+}
+
+fn exp(z: Complex) -> Complex {
+    Complex::from_polar(z.re.exp(), z.im)
+}
+
+/// `(e^(λ·t) - 1)/λ`, which tends to `t` for `λ → 0`.
+fn phi(lambda: Complex, t: f64) -> Complex {
+    let z = lambda * Complex::from(t);
+    if z.norm_sqr() < 1e-10 {
+        // Taylor series, avoiding the cancellation in e^z - 1
+        Complex::from(t) * (Complex::from(1.0) + z / Complex::from(2.0) + z * z / Complex::from(6.0))
+    } else {
+        (exp(z) - Complex::from(1.0)) / lambda
+    }
+}
+
+#[cfg(test)]
+mod ode_test;
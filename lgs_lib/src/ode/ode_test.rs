@@ -0,0 +1,91 @@
+use crate::matrix::matrix::{Complex, Matrix, Vector};
+use crate::ode::{forced_linear_system, forced_modal_decomposition, linear_system, modal_decomposition};
+use crate::test_util::assert_close;
+
+fn oscillator() -> Matrix {
+    // x'' = -4x as a first order system, x(t) = cos 2t for x(0) = 1, x'(0) = 0
+    Matrix::from_data(vec![vec![0.0, 1.0], vec![-4.0, 0.0]])
+}
+
+#[test]
+fn test_linear_system_oscillator() {
+    let times = [0.0, 0.5, 1.0, 3.0];
+    let states = linear_system(&oscillator(), &[1.0, 0.0], &times);
+    assert_eq!(states.len(), times.len());
+    for (x, t) in states.iter().zip(times) {
+        assert_close(x, &[(2.0 * t).cos(), -2.0 * (2.0 * t).sin()], 1e-12);
+    }
+}
+
+#[test]
+fn test_linear_system_decay_backwards_in_time() {
+    let a = Matrix::from_data(vec![vec![-1.0, 0.0], vec![0.0, -3.0]]);
+    let states = linear_system(&a, &[2.0, 1.0], &[-1.0, 2.0]);
+    assert_close(&states[0], &[2.0 * 1f64.exp(), 3f64.exp()], 1e-11);
+    assert_close(&states[1], &[2.0 * (-2f64).exp(), (-6f64).exp()], 1e-13);
+}
+
+#[test]
+fn test_forced_linear_system() {
+    // x' = -x + 2 approaches the equilibrium 2
+    let a = Matrix::from_data(vec![vec![-1.0]]);
+    let states = forced_linear_system(&a, &[2.0], &[0.0], &[0.0, 1.0, 50.0]);
+    assert_close(&states[0], &[0.0], 1e-15);
+    assert_close(&states[1], &[2.0 * (1.0 - (-1f64).exp())], 1e-13);
+    assert_close(&states[2], &[2.0], 1e-12);
+}
+
+#[test]
+fn test_forced_linear_system_with_singular_matrix() {
+    // constant acceleration: position t²/2, velocity t
+    let a = Matrix::from_data(vec![vec![0.0, 1.0], vec![0.0, 0.0]]);
+    let states = forced_linear_system(&a, &[0.0, 1.0], &[0.0, 0.0], &[3.0]);
+    assert_close(&states[0], &[4.5, 3.0], 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "Matrix and vector lengths do not match")]
+fn test_linear_system_length_mismatch_panics() {
+    linear_system(&oscillator(), &[1.0], &[1.0]);
+}
+
+#[test]
+fn test_modal_decomposition_matches_exponential() {
+    let a = Matrix::from_data(vec![vec![-0.5, 2.0, 0.0], vec![-2.0, -0.5, 1.0], vec![0.0, 0.0, -1.0]]);
+    let x0 = [1.0, -1.0, 2.0];
+    let modal = modal_decomposition(&a, &x0).unwrap();
+
+    let mut rates: Vec<(f64, f64)> = modal.eigenvalues().iter().map(|l| (l.re, l.im)).collect();
+    rates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_close(&[rates[0].0, rates[1].0, rates[1].1, rates[2].0, rates[2].1], &[-1.0, -0.5, -2.0, -0.5, 2.0], 1e-12);
+
+    let times = [0.0, 0.3, 2.0];
+    for (m, e) in modal.states(&times).iter().zip(linear_system(&a, &x0, &times)) {
+        assert_close(m, &e, 1e-12);
+    }
+}
+
+#[test]
+fn test_forced_modal_decomposition() {
+    let a = oscillator();
+    let b = [1.0, 0.5];
+    let x0 = [0.0, 1.0];
+    let modal = forced_modal_decomposition(&a, &b, &x0).unwrap();
+    for t in [0.0, 1.0, 4.0] {
+        assert_close(&modal.state(t), &forced_linear_system(&a, &b, &x0, &[t])[0], 1e-12);
+    }
+}
+
+#[test]
+fn test_modal_decomposition_of_complex_system() {
+    // x' = iωx rotates in the complex plane
+    let a = Matrix::from_data(vec![vec![Complex::new(0.0, 3.0)]]);
+    let x: Vector<Complex> = modal_decomposition(&a, &[Complex::from(1.0)]).unwrap().state(0.5);
+    assert!((x[0].re - 1.5f64.cos()).abs() < 1e-14 && (x[0].im - 1.5f64.sin()).abs() < 1e-14);
+}
+
+#[test]
+fn test_modal_decomposition_of_defective_matrix() {
+    let jordan = Matrix::from_data(vec![vec![1.0, 1.0], vec![0.0, 1.0]]);
+    assert!(modal_decomposition(&jordan, &[1.0, 1.0]).is_none());
+}
@@ -0,0 +1,24 @@
+//! Helpers shared by the unit tests of all modules.
+
+use crate::matrix::matrix::{Matrix, Rational, Scalar};
+
+/// Assert that two sequences agree entry by entry up to `tol`.
+pub fn assert_close<T: Scalar>(actual: &[T], expected: &[T], tol: f64) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a.clone() - e.clone()).abs() < tol, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// Assert that two matrices have the same shape and agree entry by entry up to `tol`.
+pub fn assert_matrix_close<T: Scalar>(actual: &Matrix<T>, expected: &Matrix<T>, tol: f64) {
+    assert_eq!((actual.height, actual.width), (expected.height, expected.width));
+    for i in 0..actual.height {
+        assert_close(&actual[i], &expected[i], tol);
+    }
+}
+
+/// A rational matrix from integer entries.
+pub fn rationals(data: Vec<Vec<i64>>) -> Matrix<Rational> {
+    Matrix::from_data(data.into_iter().map(|r| r.into_iter().map(Rational::from).collect()).collect())
+}
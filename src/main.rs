@@ -1,7 +1,10 @@
+use std::fmt::{Debug, Display};
 use std::process::exit;
+use std::str::FromStr;
 
-use lgs_lib::lgs::lgs;
-use lgs_lib::matrix::matrix::Matrix;
+use lgs_lib::lgs::lgs::{self, EliminationTrace};
+use lgs_lib::matrix::matrix::polynomial::format_polynomial;
+use lgs_lib::matrix::matrix::{Matrix, Rational, Scalar, Vector};
 use structopt::StructOpt;
 
 /// # Opt
@@ -16,6 +19,9 @@ use structopt::StructOpt;
 /// - _**-d**_: return determinant of the matrix passed as an argument
 /// - _**-i**_: return the inverse matrix of the matrix passed as an argument (not implemented yet)
 /// - _**-t**_: return the transposed matrix passed as an argument
+/// - _**--steps**_: with **-s** or **-i**, print the augmented matrix after every row operation, as plain text and LaTeX
+/// - _**-p**_: print the characteristic polynomial `det(x·I - M)` of the matrix
+/// - _**-e**_: calculate with exact fractions instead of floating point numbers, e.g. **[[1/3, 2],[0.5, 1]]**
 #[derive(Debug, StructOpt)]
 #[structopt(
     name = "matrix",
@@ -36,6 +42,12 @@ struct Opt {
     solve: bool,
 #[structopt(short = "a", long = "aproximate")]
     aproximate: bool,
+    #[structopt(long = "steps")]
+    steps: bool,
+    #[structopt(short = "p", long = "charpoly")]
+    char_poly: bool,
+    #[structopt(short = "e", long = "exact")]
+    exact: bool,
 }
 
 ///
@@ -48,17 +60,34 @@ fn main() {
         eprintln!("No matrix provided");
         exit(1);
     }
-    let mut matrix = Matrix::from_str(&opt.matrix[..]);
-    
+    if opt.exact {
+        run::<Rational>(opt);
+    } else {
+        run::<f64>(opt);
+    }
+}
+
+fn run<T>(opt: Opt)
+where
+    T: Scalar + FromStr + Display,
+    T::Err: Debug,
+{
+    let matrix: Matrix<T> = Matrix::from_str(&opt.matrix[..]);
+
     println!("M:{:?}", matrix.get_data());
     
     calc_determinant_if_opt(&opt,&matrix);
     calculate_inverse_if_opt(&opt, &matrix);
     transpose_if_opt(&opt,&matrix);
+    char_poly_if_opt(&opt, &matrix);
     solve_if_opt(opt,  matrix);
 }
 
-fn solve_if_opt(opt: Opt, matrix: Matrix) {
+fn solve_if_opt<T>(opt: Opt, matrix: Matrix<T>)
+where
+    T: Scalar + FromStr + Display,
+    T::Err: Debug,
+{
     
     let binding = opt.vec.replace("[", "").replace("]", "");
     if opt.solve {
@@ -66,9 +95,9 @@ fn solve_if_opt(opt: Opt, matrix: Matrix) {
             eprintln!("Invalid or empty Vector provided");
             exit(1);
         } else {
-            let b: Vec<f64> = binding
+            let b: Vector<T> = binding
                 .split(",")
-                .map(|s| s.trim().parse::<f64>().unwrap())
+                .map(|s| s.trim().parse::<T>().unwrap())
                 .collect();
 
                 let m = if opt.aproximate {
@@ -79,33 +108,61 @@ fn solve_if_opt(opt: Opt, matrix: Matrix) {
                         exit(1);
                     }
                 
-                    let clone = matrix.clone();
-                    (clone.transpose()) * clone
+                    matrix.transpose() * &matrix
                 } else {
-                    matrix.clone()
+                    matrix
                 };
                 
-            let (m, v) = lgs::solve(m, b);
+            let (m, v) = if opt.steps {
+                let mut trace = EliminationTrace::new();
+                let solved = lgs::solve_traced(m, b, &mut trace);
+                print_trace(&trace);
+                solved
+            } else {
+                lgs::solve(m, b)
+            };
             println!("M:{:?}, b: {:?}", m.get_data(), v);
         }
     }
 }
 
 
-fn transpose_if_opt(opt: &Opt, matrix: &Matrix) {
+fn transpose_if_opt<T: Scalar>(opt: &Opt, matrix: &Matrix<T>) {
     if opt.transpose {
         println!("T: {:?}", matrix.transpose().get_data());
     }
 }
 
-fn calculate_inverse_if_opt(opt: &Opt, matrix: &Matrix) {
+fn calculate_inverse_if_opt<T: Scalar + Display>(opt: &Opt, matrix: &Matrix<T>) {
     if opt.inverse {
-        let inv = lgs::inverse(matrix.clone());
+        let inv = if opt.steps {
+            let mut trace = EliminationTrace::new();
+            let inv = lgs::inverse_traced(matrix.clone(), &mut trace);
+            print_trace(&trace);
+            inv
+        } else {
+            lgs::inverse(matrix.clone())
+        };
         println!("Inverse: {:?}", inv);
     }
 }
 
-fn calc_determinant_if_opt(opt: &Opt, matrix: &Matrix) {
+fn print_trace<T: Clone + Display>(trace: &EliminationTrace<T>) {
+    println!("{}", trace.to_text());
+    println!("{}", trace.to_latex());
+}
+
+fn char_poly_if_opt<T: Scalar + Display>(opt: &Opt, matrix: &Matrix<T>) {
+    if opt.char_poly {
+        if matrix.width != matrix.height {
+            eprintln!("The characteristic polynomial is only defined for square matrices");
+            exit(1);
+        }
+        println!("p(x) = {}", format_polynomial(&matrix.char_poly(), "x"));
+    }
+}
+
+fn calc_determinant_if_opt<T: Scalar>(opt: &Opt, matrix: &Matrix<T>) {
     if opt.determinant {
         println!("Det:{:?}", matrix.det());
     }